@@ -0,0 +1,273 @@
+#!/usr/bin/env python3
+"""Generate src/languages/calendar/<code>.txt from CLDR Gregorian calendar data.
+
+Reads month, weekday and era names through the ICU C library (libicuuc), so the
+CLDR version is whichever the installed ICU ships; it is written into each file's
+header. Run from anywhere:
+
+    python3 scripts/calendar_from_cldr.py [--icu-version 72]
+
+For every code in SUPPORTED_LANGUAGES (src/lib.rs) this writes:
+
+  month   | wide and abbreviated names, format and stand-alone context
+  weekday | wide and abbreviated names, format and stand-alone context
+  era     | abbreviated eras and their variants (`v. Chr.`, `v. u. Z.`)
+
+CLDR writes some abbreviations without the period prose adds to them (`Jan`,
+`sty`, `Ιαν`), so where it has none with a period, an abbreviated form shorter
+than the wide names is also written with one (`Jan.`). CLDR only has the
+nominative and the format-context case (genitive or partitive); the other cases
+a date is written in (`в январе`, `tammikuussa`, `w styczniu`) come from
+INFLECTIONS below.
+"""
+
+import argparse
+import ctypes
+import ctypes.util
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+OUT_DIR = ROOT / "src" / "languages" / "calendar"
+
+# Regional locales whose names are common enough in the base language's text.
+REGIONAL_LOCALES = {
+    "de": ["de_AT"],
+    "en": ["en_GB"],
+    "es": ["es_419"],
+    "pt": ["pt_PT"],
+}
+
+
+# Prepositions CLDR keeps in format-context names (Catalan `de gener`, `d’abril`).
+PREPOSITIONS = {
+    "ca": re.compile(r"^(?:de |d’)"),
+}
+
+
+def fi_cases(name, endings):
+    return [name + ending for ending in endings]
+
+
+FI_MONTHS = [
+    "tammikuu", "helmikuu", "maaliskuu", "huhtikuu", "toukokuu", "kesäkuu",
+    "heinäkuu", "elokuu", "syyskuu", "lokakuu", "marraskuu", "joulukuu",
+]
+FI_WEEKDAYS = [
+    "sunnuntai", "maanantai", "tiistai", "keskiviikko", "torstai", "perjantai",
+    "lauantai",
+]
+
+# Forms beyond CLDR, per language: `months` and `weekdays` are lists of twelve
+# and seven lists of extra forms, January and Sunday first.
+INFLECTIONS = {
+    "en": {
+        # Long abbreviations CLDR does not list.
+        "months": [[], [], [], [], [], [], [], [], ["Sept."], [], [], []],
+        "weekdays": [[], [], ["Tues."], [], ["Thurs."], [], []],
+    },
+    "fi": {
+        # Inessive, elative and illative (`tammikuussa`); essive weekdays (`sunnuntaina`).
+        "months": [fi_cases(name, ["ssa", "sta", "hun"]) for name in FI_MONTHS],
+        "weekdays": [fi_cases(name, ["na"]) for name in FI_WEEKDAYS],
+    },
+    "pl": {
+        # Locative (`w styczniu`); accusative weekdays (`w środę`).
+        "months": [
+            ["styczniu"], ["lutym"], ["marcu"], ["kwietniu"], ["maju"], ["czerwcu"],
+            ["lipcu"], ["sierpniu"], ["wrześniu"], ["październiku"], ["listopadzie"],
+            ["grudniu"],
+        ],
+        "weekdays": [["niedzielę"], [], [], ["środę"], [], [], ["sobotę"]],
+    },
+    "ru": {
+        # Prepositional (`в январе`); accusative weekdays (`в среду`).
+        "months": [
+            ["январе"], ["феврале"], ["марте"], ["апреле"], ["мае"], ["июне"], ["июле"],
+            ["августе"], ["сентябре"], ["октябре"], ["ноябре"], ["декабре"],
+        ],
+        "weekdays": [[], [], [], ["среду"], [], ["пятницу"], ["субботу"]],
+    },
+    "sk": {
+        # Locative (`v januári`); accusative weekdays (`v stredu`).
+        "months": [
+            ["januári"], ["februári"], ["marci"], ["apríli"], ["máji"], ["júni"],
+            ["júli"], ["auguste"], ["septembri"], ["októbri"], ["novembri"],
+            ["decembri"],
+        ],
+        "weekdays": [["nedeľu"], [], [], ["stredu"], [], [], ["sobotu"]],
+    },
+    "uk": {
+        # Locative (`у січні`); accusative weekdays (`у середу`).
+        "months": [
+            ["січні"], ["лютому"], ["березні"], ["квітні"], ["травні"], ["червні"],
+            ["липні"], ["серпні"], ["вересні"], ["жовтні"], ["листопаді"], ["грудні"],
+        ],
+        "weekdays": [["неділю"], [], [], ["середу"], ["п’ятницю"], [], ["суботу"]],
+    },
+}
+
+
+class Icu:
+    def __init__(self, version):
+        path = ctypes.util.find_library("icuuc") or f"libicuuc.so.{version}"
+        self.lib = ctypes.CDLL(path)
+        self.suffix = f"_{version}"
+
+        p, i = ctypes.c_void_p, ctypes.c_int32
+        ip = ctypes.POINTER(ctypes.c_int32)
+        self.open = self._fn("ures_open", p, ctypes.c_char_p, ctypes.c_char_p, ip)
+        self.get = self._fn("ures_getByKeyWithFallback", p, p, ctypes.c_char_p, p, ip)
+        self.size = self._fn("ures_getSize", i, p)
+        self.string = self._fn("ures_getStringByIndex", p, p, i, ip, ip)
+        self.close = self._fn("ures_close", None, p)
+        self.icu_version = self._version("u_getVersion", self.lib)
+        i18n = ctypes.CDLL(path.replace("icuuc", "icui18n"))
+        self.cldr_version = self._version("ulocdata_getCLDRVersion", i18n, status=True)
+
+    def _fn(self, name, restype, *argtypes, lib=None):
+        fn = getattr(lib or self.lib, name + self.suffix)
+        fn.restype = restype
+        fn.argtypes = list(argtypes)
+        return fn
+
+    def _version(self, name, lib, status=False):
+        info = (ctypes.c_uint8 * 4)()
+        fn = getattr(lib, name + self.suffix)
+        if status:
+            fn(info, ctypes.byref(ctypes.c_int32(0)))
+        else:
+            fn(info)
+        return ".".join(str(part) for part in info[:2])
+
+    def strings(self, locale, *path):
+        """The string array at `calendar/gregorian/<path>` for `locale`, or []."""
+        status = ctypes.c_int32(0)
+        bundle = self.open(None, locale.encode(), ctypes.byref(status))
+        if status.value > 0:
+            return []
+
+        owned = [bundle]
+        try:
+            res = bundle
+            for key in ("calendar", "gregorian", *path):
+                status = ctypes.c_int32(0)
+                res = self.get(res, key.encode(), None, ctypes.byref(status))
+                if status.value > 0:
+                    return []
+                owned.append(res)
+
+            out = []
+            for idx in range(self.size(res)):
+                status, length = ctypes.c_int32(0), ctypes.c_int32(0)
+                ptr = self.string(res, idx, ctypes.byref(length), ctypes.byref(status))
+                raw = ctypes.string_at(ptr, length.value * 2).decode("utf-16-le")
+                out.append(normalize(raw))
+            return out
+        finally:
+            for res in reversed(owned):
+                self.close(res)
+
+
+def normalize(form):
+    """Spaces for the no-break spaces CLDR puts inside eras (`н. э.`)."""
+    return re.sub(r"[  ]", " ", form).strip()
+
+
+def supported_languages():
+    source = (ROOT / "src" / "lib.rs").read_text()
+    block = re.search(r"SUPPORTED_LANGUAGES: &\[&str\] = &\[(.*?)\];", source, re.S)
+    return re.findall(r'"([a-z]+)"', block.group(1))
+
+
+def dedupe(forms):
+    seen, out = set(), []
+    for form in forms:
+        if form and form not in seen:
+            seen.add(form)
+            out.append(form)
+    return out
+
+
+def with_periods(abbreviated, wide):
+    """Abbreviated forms, plus a period on those shorter than every wide name when CLDR
+    writes none of them with one. Only cased scripts abbreviate with a period."""
+    if any(form.endswith(".") for form in abbreviated):
+        return abbreviated
+
+    shortest = min(len(name) for name in wide)
+    out = []
+    for form in abbreviated:
+        out.append(form)
+        if len(form) < shortest and all(c.lower() != c.upper() for c in form):
+            out.append(form + ".")
+    return out
+
+
+def names(icu, code, locales, table, count):
+    """Per month or weekday, every wide and abbreviated form across `locales`."""
+    wide = [[] for _ in range(count)]
+    short = [[] for _ in range(count)]
+    for locale in locales:
+        for ctx in ("format", "stand-alone"):
+            for width, rows in (("wide", wide), ("abbreviated", short)):
+                forms = icu.strings(locale, table, ctx, width)
+                if len(forms) == count:
+                    for row, form in zip(rows, forms):
+                        row.append(form)
+
+    preposition = PREPOSITIONS.get(code)
+    if preposition:
+        wide = [[preposition.sub("", form) for form in row] for row in wide]
+        short = [[preposition.sub("", form) for form in row] for row in short]
+
+    return [w + with_periods(s, w) for w, s in zip(wide, short)]
+
+
+def render(icu, code):
+    locales = [code] + REGIONAL_LOCALES.get(code, [])
+    months = names(icu, code, locales, "monthNames", 12)
+    weekdays = names(icu, code, locales, "dayNames", 7)
+    extra = INFLECTIONS.get(code, {})
+    for rows, key in ((months, "months"), (weekdays, "weekdays")):
+        for row, forms in zip(rows, extra.get(key, [])):
+            row += forms
+
+    eras = []
+    for locale in locales:
+        for key in ("abbreviated", "abbreviated%variant"):
+            eras += icu.strings(locale, "eras", key)
+
+    if not any(months) or not any(weekdays):
+        sys.exit(f"no calendar data for `{code}` in ICU {icu.icu_version}")
+
+    header = [
+        f"// Calendar names for `{code}`, from the CLDR {icu.cldr_version} Gregorian calendar"
+        f" (ICU {icu.icu_version}).",
+        "// Generated by scripts/calendar_from_cldr.py; edit the script, not this file.",
+        "// Format: see `CalendarNames::parse` in src/languages/calendar.rs.",
+    ]
+    lines = header + [""]
+    lines += ["month   | " + " | ".join(dedupe(row)) for row in months]
+    lines.append("")
+    lines += ["weekday | " + " | ".join(dedupe(row)) for row in weekdays]
+    if dedupe(eras):
+        lines += ["", "era     | " + " | ".join(dedupe(eras))]
+    return "\n".join(lines) + "\n"
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--icu-version", default="72", help="ICU major version (symbol suffix)")
+    args = parser.parse_args()
+
+    icu = Icu(args.icu_version)
+    for code in supported_languages():
+        path = OUT_DIR / f"{code}.txt"
+        path.write_text(render(icu, code))
+        print(f"wrote {path.relative_to(ROOT)}")
+
+
+if __name__ == "__main__":
+    main()
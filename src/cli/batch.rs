@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+use sentencex::languages::Language;
+use sentencex::{SentenceBoundary, get_sentence_boundaries_with_language};
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use super::output::{OutputFormat, write_boundaries};
+
+/// One input file and the path it is mirrored to under `--output-dir`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchInput {
+    pub path: PathBuf,
+    pub relative: PathBuf,
+}
+
+/// A boundary tagged with the file it came from, for the combined JSONL stream.
+#[derive(Serialize)]
+struct TaggedBoundary<'a> {
+    path: &'a str,
+    #[serde(flatten)]
+    boundary: &'a SentenceBoundary<'a>,
+}
+
+/// Where batch results go.
+pub enum BatchSink<'a> {
+    /// One output file per input, mirroring the input layout under this directory.
+    Mirror(&'a Path),
+    /// One JSONL stream on stdout, each record tagged with its source path.
+    Stream,
+}
+
+/// Expand `paths` into the list of files to segment. Directories are walked
+/// recursively and their files kept only when the file name matches one of
+/// `include` (all files when `include` is empty). Files named directly are
+/// always kept. Unreadable directory entries are reported and skipped.
+pub fn collect_inputs(
+    paths: &[PathBuf],
+    include: &[String],
+    errors: &AtomicUsize,
+) -> Vec<BatchInput> {
+    let mut inputs = Vec::new();
+
+    for root in paths {
+        if !root.is_dir() {
+            let relative = root.file_name().map_or_else(|| root.clone(), PathBuf::from);
+            inputs.push(BatchInput {
+                path: root.clone(),
+                relative,
+            });
+            continue;
+        }
+
+        for entry in WalkDir::new(root).sort_by_file_name() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    report_error(&root.display().to_string(), &err, errors);
+                    continue;
+                }
+            };
+
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy();
+            if !include.is_empty() && !include.iter().any(|p| wildcard_match(p, &name)) {
+                continue;
+            }
+
+            let relative = entry
+                .path()
+                .strip_prefix(root)
+                .unwrap_or(entry.path())
+                .to_path_buf();
+
+            inputs.push(BatchInput {
+                path: entry.into_path(),
+                relative,
+            });
+        }
+    }
+
+    inputs
+}
+
+/// The first two inputs that would be written to the same file under
+/// `--output-dir` (`a/x.txt` and `b/x.txt` both mirror to `x.txt`).
+pub fn find_output_collision(inputs: &[BatchInput]) -> Option<(&Path, &Path)> {
+    let mut seen: HashMap<&Path, &Path> = HashMap::with_capacity(inputs.len());
+
+    for input in inputs {
+        if let Some(first) = seen.insert(&input.relative, &input.path) {
+            return Some((first, &input.path));
+        }
+    }
+
+    None
+}
+
+/// Segment every input in parallel and write the results to `sink`.
+/// A file that cannot be read or written is reported on stderr and counted in
+/// `errors`; the remaining files are still processed.
+pub fn run_batch(
+    inputs: &[BatchInput],
+    language: &dyn Language,
+    format: OutputFormat,
+    sink: &BatchSink,
+    errors: &AtomicUsize,
+) {
+    let stdout = Mutex::new(io::stdout());
+
+    inputs.par_iter().for_each(|input| {
+        let display = input.path.display().to_string();
+
+        let text = match fs::read_to_string(&input.path) {
+            Ok(text) => text,
+            Err(err) => return report_error(&display, &err, errors),
+        };
+
+        let boundaries = get_sentence_boundaries_with_language(language, &text);
+
+        let result = match sink {
+            BatchSink::Mirror(output_dir) => {
+                write_mirrored(output_dir, &input.relative, format, &boundaries)
+            }
+            BatchSink::Stream => {
+                let mut buffer = Vec::new();
+                write_tagged(&mut buffer, &display, &boundaries).and_then(|_| {
+                    let mut out = stdout.lock().unwrap_or_else(|e| e.into_inner());
+                    out.write_all(&buffer)
+                })
+            }
+        };
+
+        if let Err(err) = result {
+            report_error(&display, &err, errors);
+        }
+    });
+}
+
+/// Write `boundaries` for one input to `<output_dir>/<relative>.<ext>`,
+/// creating intermediate directories as needed.
+fn write_mirrored(
+    output_dir: &Path,
+    relative: &Path,
+    format: OutputFormat,
+    boundaries: &[SentenceBoundary],
+) -> io::Result<()> {
+    let mut target = output_dir.join(relative).into_os_string();
+    target.push(".");
+    target.push(format.extension());
+    let target = PathBuf::from(target);
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut out = io::BufWriter::new(fs::File::create(&target)?);
+    write_boundaries(&mut out, format, boundaries)?;
+    out.flush()
+}
+
+fn write_tagged<W: Write>(
+    out: &mut W,
+    path: &str,
+    boundaries: &[SentenceBoundary],
+) -> io::Result<()> {
+    for boundary in boundaries {
+        serde_json::to_writer(&mut *out, &TaggedBoundary { path, boundary })?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn report_error(path: &str, err: &dyn std::fmt::Display, errors: &AtomicUsize) {
+    eprintln!("error: {path}: {err}");
+    errors.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Match `name` against a shell-style wildcard `pattern` supporting `*` (any
+/// run of characters) and `?` (exactly one character).
+pub fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wildcard_match_handles_star_and_question_mark() {
+        assert!(wildcard_match("*.txt", "article.txt"));
+        assert!(wildcard_match("*.txt", ".txt"));
+        assert!(wildcard_match("a?c*", "abcdef"));
+        assert!(wildcard_match("*", "anything"));
+        assert!(!wildcard_match("*.txt", "article.txt.bak"));
+        assert!(!wildcard_match("a?c", "ac"));
+    }
+
+    #[test]
+    fn collect_inputs_walks_directories_with_filters() {
+        let root = std::env::temp_dir().join(format!("sentencex-batch-{}", std::process::id()));
+        fs::create_dir_all(root.join("nested")).unwrap();
+        fs::write(root.join("a.txt"), "A.").unwrap();
+        fs::write(root.join("b.json"), "{}").unwrap();
+        fs::write(root.join("nested/c.txt"), "C.").unwrap();
+
+        let errors = AtomicUsize::new(0);
+        let inputs = collect_inputs(std::slice::from_ref(&root), &["*.txt".to_string()], &errors);
+        let relative: Vec<_> = inputs.iter().map(|i| i.relative.clone()).collect();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(errors.load(Ordering::Relaxed), 0);
+        assert_eq!(
+            relative,
+            vec![PathBuf::from("a.txt"), PathBuf::from("nested/c.txt")]
+        );
+    }
+
+    #[test]
+    fn finds_inputs_mirrored_to_the_same_output() {
+        let input = |path: &str| BatchInput {
+            path: PathBuf::from(path),
+            relative: PathBuf::from(Path::new(path).file_name().unwrap()),
+        };
+
+        let inputs = [input("a/x.txt"), input("a/y.txt"), input("b/x.txt")];
+        assert_eq!(
+            find_output_collision(&inputs),
+            Some((Path::new("a/x.txt"), Path::new("b/x.txt")))
+        );
+        assert_eq!(find_output_collision(&inputs[..2]), None);
+    }
+
+    #[test]
+    fn tagged_records_carry_the_source_path() {
+        let boundaries = sentencex::get_sentence_boundaries("en", "One. Two.");
+        let mut out = Vec::new();
+        write_tagged(&mut out, "dir/a.txt", &boundaries).unwrap();
+
+        let first = String::from_utf8(out).unwrap();
+        let record: serde_json::Value =
+            serde_json::from_str(first.lines().next().unwrap()).unwrap();
+
+        assert_eq!(record["path"], "dir/a.txt");
+        assert_eq!(record["text"], "One. ");
+        assert_eq!(record["end_byte"], 5);
+    }
+}
@@ -0,0 +1,177 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use sentencex::fixtures::{normalise_sentences, parse_fixtures};
+use sentencex::metrics::BoundaryScores;
+use sentencex::segment;
+
+/// Score segmentation against `===`/`---` fixture files; exits non-zero if any case fails
+#[derive(Args, Debug)]
+pub struct EvalArgs {
+    /// Fixture files in the `tests/*.txt` format
+    #[arg(required = true)]
+    fixtures: Vec<PathBuf>,
+
+    /// The language of the fixtures
+    #[arg(short, long, default_value = "en")]
+    language: String,
+
+    /// Only print the summary, not the failing cases
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+/// Boundary positions of `sentences`, measured in non-whitespace characters so
+/// gold and predicted segmentations compare independent of spacing. The end of
+/// the last sentence is not a boundary.
+fn boundary_offsets(sentences: &[String]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(sentences.len());
+    let mut position = 0;
+
+    for sentence in sentences {
+        position += sentence.chars().filter(|c| !c.is_whitespace()).count();
+        offsets.push(position);
+    }
+
+    offsets.pop();
+    offsets
+}
+
+/// `numerator / denominator`, or 1.0 when there is nothing to measure.
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        1.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// Line diff of `expected` against `actual` (longest common subsequence):
+/// unchanged lines are prefixed with two spaces, removals with `- ` and
+/// additions with `+ `.
+fn diff_lines(expected: &[String], actual: &[String]) -> Vec<String> {
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            out.push(format!("  {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            out.push(format!("+ {}", actual[j]));
+            j += 1;
+        } else {
+            out.push(format!("- {}", expected[i]));
+            i += 1;
+        }
+    }
+
+    out
+}
+
+pub fn run(args: EvalArgs) -> ExitCode {
+    let mut counts = BoundaryScores::default();
+    let (mut total, mut exact) = (0, 0);
+
+    for path in &args.fixtures {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) => {
+                eprintln!("error: {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for case in parse_fixtures(&content) {
+            let actual = normalise_sentences(segment(&args.language, &case.input));
+
+            counts.add(
+                &boundary_offsets(&case.expected),
+                &boundary_offsets(&actual),
+            );
+
+            total += 1;
+            if actual == case.expected {
+                exact += 1;
+                continue;
+            }
+
+            if !args.quiet {
+                println!("=== FAIL {}:{}", path.display(), case.line);
+                println!("{}", case.input);
+                println!("---");
+                for line in diff_lines(&case.expected, &actual) {
+                    println!("{line}");
+                }
+                println!();
+            }
+        }
+    }
+
+    println!(
+        "Cases: {total}  Exact match: {:.2}% ({exact}/{total})",
+        100.0 * ratio(exact, total)
+    );
+    println!(
+        "Boundaries: precision {:.4}  recall {:.4}  F1 {:.4}  (tp {}, fp {}, fn {})",
+        counts.precision(),
+        counts.recall(),
+        counts.f1(),
+        counts.true_positives,
+        counts.false_positives,
+        counts.false_negatives
+    );
+
+    if exact == total {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn boundary_counts_ignore_whitespace_differences() {
+        let gold = boundary_offsets(&strings(&["A b.", "C d.", "E."]));
+        let predicted = boundary_offsets(&strings(&["A b. C d.", "E."]));
+
+        let mut counts = BoundaryScores::default();
+        counts.add(&gold, &predicted);
+
+        assert_eq!(gold, vec![3, 6]);
+        assert_eq!(predicted, vec![6]);
+        assert_eq!(counts.true_positives, 1);
+        assert_eq!(counts.false_negatives, 1);
+        assert_eq!(counts.precision(), 1.0);
+        assert_eq!(counts.recall(), 0.5);
+    }
+
+    #[test]
+    fn diff_lines_marks_changes() {
+        let diff = diff_lines(&strings(&["A.", "B.", "C."]), &strings(&["A. B.", "C."]));
+        assert_eq!(diff, strings(&["+ A. B.", "- A.", "- B.", "  C."]));
+    }
+}
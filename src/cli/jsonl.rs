@@ -0,0 +1,103 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use sentencex::jsonl::{JsonlMode, JsonlOptions, segment_jsonl};
+
+/// Segment a text field of JSONL records and write each record back with the result
+#[derive(Args, Debug)]
+pub struct JsonlArgs {
+    /// JSONL file to read (defaults to stdin)
+    input: Option<PathBuf>,
+
+    /// Field holding the text to segment
+    #[arg(long, default_value = "text")]
+    text_field: String,
+
+    /// Field holding a per-record language code
+    #[arg(long, default_value = "lang")]
+    lang_field: String,
+
+    /// Language used for records without a language field
+    #[arg(short, long, default_value = "en")]
+    language: String,
+
+    /// Field the sentences or boundaries are written to
+    #[arg(long, default_value = "sentences")]
+    output_field: String,
+
+    /// What to write back for each record
+    #[arg(long, value_enum, default_value_t = ModeArg::Sentences)]
+    mode: ModeArg,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ModeArg {
+    /// Add an array of sentence strings
+    Sentences,
+    /// Add an array of boundary objects with offsets
+    Boundaries,
+    /// Write one record per sentence
+    Explode,
+}
+
+impl From<ModeArg> for JsonlMode {
+    fn from(mode: ModeArg) -> Self {
+        match mode {
+            ModeArg::Sentences => JsonlMode::Sentences,
+            ModeArg::Boundaries => JsonlMode::Boundaries,
+            ModeArg::Explode => JsonlMode::Explode,
+        }
+    }
+}
+
+pub fn run(args: JsonlArgs) -> ExitCode {
+    let options = JsonlOptions {
+        text_field: args.text_field,
+        language_field: Some(args.lang_field),
+        default_language: args.language,
+        output_field: args.output_field,
+        mode: args.mode.into(),
+    };
+
+    let input: Box<dyn BufRead> = match &args.input {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(err) => {
+                eprintln!("error: {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    let summary = match segment_jsonl(input, &mut out, &options).and_then(|s| {
+        out.flush()?;
+        Ok(s)
+    }) {
+        Ok(summary) => summary,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for (line, err) in &summary.errors {
+        eprintln!("error: line {line}: {err}");
+    }
+
+    eprintln!(
+        "Segmented {} records ({} errors)",
+        summary.records,
+        summary.errors.len()
+    );
+
+    if summary.errors.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,6 @@
+pub mod batch;
+pub mod eval;
+pub mod jsonl;
+pub mod modes;
+pub mod output;
+pub mod serve;
@@ -0,0 +1,44 @@
+use clap::Args;
+use sentencex::language_factory;
+use sentencex::languages::{Language, SocialText, TechnicalText};
+
+/// Text modes layered on top of the language.
+#[derive(Args, Debug, Clone, Copy)]
+pub struct ModeArgs {
+    /// Technical text: keep code tokens (`foo.bar()`, `std::io::Read`,
+    /// `$HOME/.config`, backtick spans) inside their sentence
+    #[arg(long)]
+    technical: bool,
+
+    /// Social-media text: attach emoji and emoticon runs to the sentence
+    /// before them and keep dotted hashtags and mentions whole
+    #[arg(long)]
+    social: bool,
+
+    /// With `--social`, also end a sentence after an emoji or emoticon run
+    /// followed by a capitalised word
+    #[arg(long, requires = "social")]
+    emoji_boundaries: bool,
+}
+
+impl ModeArgs {
+    /// The language for `code`, wrapped in the selected text modes.
+    pub fn language(&self, code: &str) -> Box<dyn Language> {
+        let mut language = language_factory(code);
+
+        if self.technical {
+            language = Box::new(TechnicalText::new(language));
+        }
+
+        if self.social {
+            let social = SocialText::new(language);
+            language = if self.emoji_boundaries {
+                Box::new(social.with_emoji_boundaries())
+            } else {
+                Box::new(social)
+            };
+        }
+
+        language
+    }
+}
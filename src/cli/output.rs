@@ -0,0 +1,152 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use sentencex::SentenceBoundary;
+
+/// How the CLI renders segmentation results on stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// One `* sentence` line per sentence (human-oriented).
+    #[default]
+    Text,
+    /// A single JSON array of boundary objects.
+    Json,
+    /// One JSON boundary object per line.
+    Jsonl,
+    /// Tab-separated columns with a header row.
+    Tsv,
+}
+
+impl OutputFormat {
+    /// File extension used for this format when writing to an output directory.
+    pub fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Text => "txt",
+            OutputFormat::Json => "json",
+            OutputFormat::Jsonl => "jsonl",
+            OutputFormat::Tsv => "tsv",
+        }
+    }
+}
+
+const TSV_HEADER: &str =
+    "start_index\tend_index\tstart_byte\tend_byte\tboundary_symbol\tis_paragraph_break\ttext";
+
+/// Write `boundaries` to `out` in the structured `format`.
+/// `OutputFormat::Text` writes the `* sentence` lines the CLI has always printed.
+pub fn write_boundaries<W: Write>(
+    out: &mut W,
+    format: OutputFormat,
+    boundaries: &[SentenceBoundary],
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Text => {
+            for boundary in boundaries {
+                writeln!(out, "* {}", boundary.text)?;
+            }
+        }
+
+        OutputFormat::Json => {
+            serde_json::to_writer(&mut *out, boundaries)?;
+            writeln!(out)?;
+        }
+
+        OutputFormat::Jsonl => {
+            for boundary in boundaries {
+                serde_json::to_writer(&mut *out, boundary)?;
+                writeln!(out)?;
+            }
+        }
+
+        OutputFormat::Tsv => {
+            writeln!(out, "{TSV_HEADER}")?;
+            for boundary in boundaries {
+                write_tsv_row(out, boundary)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_tsv_row<W: Write>(out: &mut W, boundary: &SentenceBoundary) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        boundary.start_index,
+        boundary.end_index,
+        boundary.start_byte,
+        boundary.end_byte,
+        escape_tsv(boundary.boundary_symbol.unwrap_or("")),
+        boundary.is_paragraph_break,
+        escape_tsv(boundary.text),
+    )
+}
+
+/// Escape the characters that would break a TSV row: backslash, tab, newline
+/// and carriage return become `\\`, `\t`, `\n` and `\r`.
+pub fn escape_tsv(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+
+    for c in field.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sentencex::get_sentence_boundaries;
+
+    fn render(format: OutputFormat, text: &str) -> String {
+        let boundaries = get_sentence_boundaries("en", text);
+        let mut out = Vec::new();
+        write_boundaries(&mut out, format, &boundaries).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn escape_tsv_escapes_separators() {
+        assert_eq!(escape_tsv("a\tb\nc\r\\d"), "a\\tb\\nc\\r\\\\d");
+        assert_eq!(escape_tsv("plain"), "plain");
+    }
+
+    #[test]
+    fn jsonl_emits_one_object_per_boundary() {
+        let rendered = render(OutputFormat::Jsonl, "One.\n\nTwo.");
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            r#"{"start_index":4,"end_index":6,"start_byte":4,"end_byte":6,"text":"\n\n","boundary_symbol":null,"is_paragraph_break":true}"#
+        );
+    }
+
+    #[test]
+    fn tsv_keeps_multiline_sentences_on_one_row() {
+        let rendered = render(OutputFormat::Tsv, "Line one\nstill one. Two.");
+        let rows: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(rows[0], TSV_HEADER);
+        assert_eq!(rows[1], "0\t20\t0\t20\t.\tfalse\tLine one\\nstill one. ");
+        assert_eq!(rows[2], "20\t24\t20\t24\t.\tfalse\tTwo.");
+    }
+
+    #[test]
+    fn json_is_a_single_array() {
+        let rendered = render(OutputFormat::Json, "One. Two.");
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+
+        assert_eq!(value.as_array().map(Vec::len), Some(2));
+        assert_eq!(value[1]["text"], "Two.");
+    }
+}
@@ -0,0 +1,367 @@
+use std::io::Read;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::thread;
+
+use clap::Args;
+use rustc_hash::FxHashMap;
+use sentencex::languages::{LANGUAGE_FALLBACKS, Language};
+use sentencex::{
+    SUPPORTED_LANGUAGES, get_sentence_boundaries_with_language, language_factory,
+    segment_with_language,
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Serve segmentation over HTTP on a local port
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// Address to bind
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 8000)]
+    port: u16,
+
+    /// Language used when a request does not name one
+    #[arg(short, long, default_value = "en")]
+    language: String,
+
+    /// Number of worker threads handling requests
+    #[arg(long, default_value_t = 4)]
+    threads: usize,
+}
+
+/// Body of `POST /segment` and `POST /boundaries`: one `text` or a batch of `texts`.
+#[derive(Deserialize)]
+struct SegmentRequest {
+    language: Option<String>,
+    text: Option<String>,
+    texts: Option<Vec<String>>,
+}
+
+/// Largest request body accepted, in bytes. Larger bodies get a 413 response.
+const MAX_BODY_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Requested codes remembered per worker. Codes past this are resolved on each
+/// request, so clients sending arbitrary codes cannot grow the cache.
+const MAX_REMEMBERED_CODES: usize = 1024;
+
+/// Language instances built so far, keyed by the code they resolve to after
+/// fallbacks, so the cache never holds more than one entry per dedicated language
+/// however many codes clients send. Requested codes map to that key, so a code
+/// seen before is served without building its language again. Each worker thread
+/// owns one, so lookups never contend.
+#[derive(Default)]
+pub struct LanguageCache {
+    languages: FxHashMap<&'static str, Box<dyn Language>>,
+    resolved: FxHashMap<String, &'static str>,
+    #[cfg(test)]
+    builds: usize,
+}
+
+impl LanguageCache {
+    fn get(&mut self, code: &str) -> &dyn Language {
+        if let Some(&resolved) = self.resolved.get(code) {
+            return &*self.languages[resolved];
+        }
+
+        let language = language_factory(code);
+        #[cfg(test)]
+        {
+            self.builds += 1;
+        }
+        let resolved = language
+            .get_language_code()
+            .expect("bundled languages report their code");
+
+        if self.resolved.len() < MAX_REMEMBERED_CODES {
+            self.resolved.insert(code.to_string(), resolved);
+        }
+
+        &**self.languages.entry(resolved).or_insert(language)
+    }
+}
+
+pub fn run(args: ServeArgs) -> ExitCode {
+    let address = format!("{}:{}", args.host, args.port);
+    let server = match Server::http(&address) {
+        Ok(server) => Arc::new(server),
+        Err(err) => {
+            eprintln!("error: cannot listen on {address}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    eprintln!("Listening on http://{address}");
+    serve(server, args.threads.max(1), &args.language);
+
+    ExitCode::SUCCESS
+}
+
+/// Answer requests on `server` from `threads` worker threads until the server shuts down.
+pub fn serve(server: Arc<Server>, threads: usize, default_language: &str) {
+    let workers: Vec<_> = (0..threads)
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let default_language = default_language.to_string();
+
+            thread::spawn(move || {
+                let mut cache = LanguageCache::default();
+                for request in server.incoming_requests() {
+                    respond(request, &mut cache, &default_language);
+                }
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+}
+
+fn respond(mut request: Request, cache: &mut LanguageCache, default_language: &str) {
+    let mut body = String::new();
+    let too_large = (
+        413,
+        json!({ "error": format!("request body exceeds {MAX_BODY_BYTES} bytes") }),
+    );
+
+    let (status, value) = if request
+        .body_length()
+        .is_some_and(|len| len as u64 > MAX_BODY_BYTES)
+    {
+        too_large
+    } else {
+        // A chunked body has no declared length, so stop reading one byte past the limit.
+        match request
+            .as_reader()
+            .take(MAX_BODY_BYTES + 1)
+            .read_to_string(&mut body)
+        {
+            Ok(read) if read as u64 > MAX_BODY_BYTES => too_large,
+            Ok(_) => route(
+                request.method(),
+                request.url(),
+                &body,
+                cache,
+                default_language,
+            ),
+            Err(err) => (400, json!({ "error": format!("unreadable body: {err}") })),
+        }
+    };
+
+    let content_type =
+        Header::from_bytes("Content-Type", "application/json").expect("static header is valid");
+    let response = Response::from_string(value.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+
+    if let Err(err) = request.respond(response) {
+        eprintln!("error: failed to send response: {err}");
+    }
+}
+
+/// Dispatch one request and return the status code and JSON body to send.
+pub fn route(
+    method: &Method,
+    url: &str,
+    body: &str,
+    cache: &mut LanguageCache,
+    default_language: &str,
+) -> (u16, Value) {
+    let path = url.split('?').next().unwrap_or(url);
+
+    match (method, path) {
+        (Method::Get, "/health") => (200, json!({ "status": "ok" })),
+        (Method::Get, "/languages") => (200, languages()),
+        (Method::Post, "/segment") => segment_request(body, cache, default_language, false),
+        (Method::Post, "/boundaries") => segment_request(body, cache, default_language, true),
+        (_, "/health" | "/languages" | "/segment" | "/boundaries") => (
+            405,
+            json!({ "error": format!("method {method} not allowed on {path}") }),
+        ),
+        _ => (404, json!({ "error": format!("no route for {path}") })),
+    }
+}
+
+fn languages() -> Value {
+    let fallbacks: serde_json::Map<String, Value> = LANGUAGE_FALLBACKS
+        .iter()
+        .map(|(code, chain)| (code.to_string(), json!(chain)))
+        .collect();
+
+    json!({ "languages": SUPPORTED_LANGUAGES, "fallbacks": fallbacks })
+}
+
+fn segment_request(
+    body: &str,
+    cache: &mut LanguageCache,
+    default_language: &str,
+    boundaries: bool,
+) -> (u16, Value) {
+    let request: SegmentRequest = match serde_json::from_str(body) {
+        Ok(request) => request,
+        Err(err) => return (400, json!({ "error": format!("invalid JSON body: {err}") })),
+    };
+
+    let code = request.language.as_deref().unwrap_or(default_language);
+    let language = cache.get(code);
+
+    let process = |text: &str| -> Value {
+        if boundaries {
+            json!(get_sentence_boundaries_with_language(language, text))
+        } else {
+            json!(segment_with_language(language, text))
+        }
+    };
+
+    let key = if boundaries {
+        "boundaries"
+    } else {
+        "sentences"
+    };
+
+    match (request.text, request.texts) {
+        (Some(text), None) => (200, json!({ key: process(&text) })),
+        (None, Some(texts)) => {
+            let results: Vec<Value> = texts.iter().map(|t| process(t)).collect();
+            (200, json!({ "results": results }))
+        }
+        _ => (
+            400,
+            json!({ "error": "request must contain exactly one of `text` or `texts`" }),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    fn call(method: Method, url: &str, body: &str) -> (u16, Value) {
+        route(&method, url, body, &mut LanguageCache::default(), "en")
+    }
+
+    #[test]
+    fn segment_returns_sentences_for_one_text_or_a_batch() {
+        let (status, value) = call(Method::Post, "/segment", r#"{"text":"One. Two."}"#);
+        assert_eq!(status, 200);
+        assert_eq!(value, json!({ "sentences": ["One. ", "Two."] }));
+
+        let (status, value) = call(
+            Method::Post,
+            "/segment",
+            r#"{"language":"fr","texts":["Un. Deux.","Trois."]}"#,
+        );
+        assert_eq!(status, 200);
+        assert_eq!(value["results"][0], json!(["Un. ", "Deux."]));
+        assert_eq!(value["results"][1], json!(["Trois."]));
+    }
+
+    #[test]
+    fn boundaries_returns_sentence_boundary_json() {
+        let (status, value) = call(Method::Post, "/boundaries", r#"{"text":"One. Two."}"#);
+
+        assert_eq!(status, 200);
+        assert_eq!(value["boundaries"][1]["start_byte"], 5);
+        assert_eq!(value["boundaries"][1]["boundary_symbol"], ".");
+    }
+
+    #[test]
+    fn bad_requests_are_rejected() {
+        assert_eq!(call(Method::Post, "/segment", "nope").0, 400);
+        assert_eq!(
+            call(Method::Post, "/segment", r#"{"language":"en"}"#).0,
+            400
+        );
+        assert_eq!(call(Method::Get, "/segment", "").0, 405);
+        assert_eq!(call(Method::Get, "/missing", "").0, 404);
+    }
+
+    #[test]
+    fn cache_is_keyed_by_the_resolved_language() {
+        let mut cache = LanguageCache::default();
+        for code in ["en", "xx", "yy", "en-GB", "de", "als"] {
+            cache.get(code);
+        }
+
+        let mut codes: Vec<_> = cache.languages.keys().copied().collect();
+        codes.sort_unstable();
+        assert_eq!(codes, ["de", "en"]);
+        assert_eq!(cache.builds, 6);
+
+        for code in ["en", "xx", "als"] {
+            cache.get(code);
+        }
+        assert_eq!(cache.builds, 6);
+    }
+
+    #[test]
+    fn languages_lists_codes_and_fallbacks() {
+        let (status, value) = call(Method::Get, "/languages", "");
+
+        assert_eq!(status, 200);
+        assert!(
+            value["languages"]
+                .as_array()
+                .unwrap()
+                .contains(&json!("en"))
+        );
+        assert_eq!(value["fallbacks"]["als"], json!(["gsw", "de"]));
+    }
+
+    #[test]
+    fn serves_requests_on_localhost() {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let port = server.server_addr().to_ip().unwrap().port();
+
+        let background = Arc::clone(&server);
+        thread::spawn(move || serve(background, 1, "en"));
+
+        let body = r#"{"text":"Hello there. Bye."}"#;
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        write!(
+            stream,
+            "POST /segment HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+             Content-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        server.unblock();
+
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.ends_with(r#"{"sentences":["Hello there. ","Bye."]}"#));
+    }
+
+    #[test]
+    fn rejects_oversized_bodies_before_reading_them() {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let port = server.server_addr().to_ip().unwrap().port();
+
+        let background = Arc::clone(&server);
+        thread::spawn(move || serve(background, 1, "en"));
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        write!(
+            stream,
+            "POST /segment HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+             Content-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        server.unblock();
+
+        assert!(response.starts_with("HTTP/1.1 413"), "{response}");
+    }
+}
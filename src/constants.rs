@@ -113,6 +113,11 @@ pub const QUOTE_PAIRS: &[QuotePair] = &[
         close: "」",
         ambiguous: false,
     },
+    QuotePair {
+        open: "『",
+        close: "』",
+        ambiguous: false,
+    },
 ];
 
 pub static PARENS_REGEX: LazyLock<Regex> =
@@ -449,6 +454,7 @@ mod tests {
             ("German-style: „Hallo“", vec!["„Hallo“"]),
             ("Single angular: ‹Bonjour›", vec!["‹Bonjour›"]),
             ("CJK: 「こんにちは」", vec!["「こんにちは」"]),
+            ("CJK double: 『你好』", vec!["『你好』"]),
             ("Chinese: 《你好》", vec!["《你好》"]),
             ("LaTeX double: ``Hello''", vec!["``Hello''"]),
             ("LaTeX single: `Hello'", vec!["`Hello'"]),
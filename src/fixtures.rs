@@ -0,0 +1,99 @@
+//! The fixture format used by `tests/*.txt`: cases separated by `===`, input
+//! and expected sentences (one per line) separated by `---`, and `#` lines as
+//! comments. Expected and produced sentences are compared with whitespace
+//! normalised, so fixtures can wrap and indent freely.
+
+/// One fixture case: the input text and its expected sentences.
+#[derive(Debug, PartialEq)]
+pub struct FixtureCase {
+    /// 1-based line of the input's first line in the fixture file.
+    pub line: usize,
+    pub input: String,
+    pub expected: Vec<String>,
+}
+
+/// Parse fixture cases from `content`. Chunks without exactly an input and an
+/// expected part are skipped.
+pub fn parse_fixtures(content: &str) -> Vec<FixtureCase> {
+    let content = content.replace("\r\n", "\n");
+    let mut cases = Vec::new();
+    // Line of the chunk's first line: the `===` line it follows, or line 1.
+    let mut chunk_line = 1;
+
+    for chunk in content.split("===") {
+        let first_line = chunk_line;
+        chunk_line += chunk.matches('\n').count();
+
+        // Explicit '#' comment handling. This lets fixture files include
+        // section headers and structural comments anywhere — not just at
+        // the very top of the file — without silently skipping cases that
+        // happen to share a chunk with leading comments.
+        let kept: Vec<(usize, &str)> = chunk
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim_start().starts_with('#'))
+            .collect();
+
+        let cleaned = kept
+            .iter()
+            .map(|&(_, line)| line)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let parts: Vec<&str> = cleaned
+            .split("---")
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        if parts.len() != 2 {
+            continue;
+        }
+
+        let Some(&(offset, _)) = kept.iter().find(|(_, line)| !line.trim().is_empty()) else {
+            continue;
+        };
+
+        cases.push(FixtureCase {
+            line: first_line + offset,
+            input: parts[0].to_string(),
+            expected: normalise_sentences(parts[1].lines()),
+        });
+    }
+
+    cases
+}
+
+/// Collapse every whitespace run in each sentence to a single space and drop
+/// the ones left empty, the form fixture sentences are compared in.
+pub fn normalise_sentences<'a>(sentences: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    sentences
+        .into_iter()
+        .map(|s| s.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_cases_and_skips_comments() {
+        let content = "# header\n\nOne. Two.\n---\nOne.\nTwo.\n===\n# note\nThree.\n---\nThree.\n===\nbroken\n";
+        let cases = parse_fixtures(content);
+
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].line, 3);
+        assert_eq!(cases[0].input, "One. Two.");
+        assert_eq!(cases[0].expected, vec!["One.", "Two."]);
+        assert_eq!(cases[1].line, 9);
+        assert_eq!(cases[1].input, "Three.");
+    }
+
+    #[test]
+    fn normalises_whitespace_in_expected_sentences() {
+        let cases = parse_fixtures("A  b.\n---\n  A\tb.  \n\n===\n");
+        assert_eq!(cases[0].expected, vec!["A b."]);
+    }
+}
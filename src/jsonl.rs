@@ -0,0 +1,335 @@
+//! Segment a text field of JSONL records, for data pipelines that carry
+//! corpora as `{"id": …, "lang": "fr", "text": …}` lines.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use serde_json::{Map, Value};
+
+use crate::{SentenceBoundary, get_sentence_boundaries};
+
+/// What to write back for each segmented record.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonlMode {
+    /// Add an array of sentence strings under `output_field`.
+    #[default]
+    Sentences,
+    /// Add an array of `SentenceBoundary` objects (offsets, symbol, paragraph flag)
+    /// under `output_field`.
+    Boundaries,
+    /// Emit one record per sentence: the original record with `text_field` replaced
+    /// by the sentence and `sentence_index`, `start_byte` and `end_byte` added. The
+    /// sentence is trimmed, and the offsets locate the trimmed text in the original.
+    /// A record with no sentences (empty or blank text) yields one record with an
+    /// empty text and zero offsets, so every input record has output.
+    Explode,
+}
+
+/// Field names and defaults used when segmenting JSONL records.
+#[derive(Debug, Clone)]
+pub struct JsonlOptions {
+    /// Field holding the text to segment.
+    pub text_field: String,
+    /// Field holding a per-record language code. Records without it (or with a
+    /// non-string value) use `default_language`.
+    pub language_field: Option<String>,
+    /// Language code used when a record names none.
+    pub default_language: String,
+    /// Field the sentences or boundaries are written to.
+    pub output_field: String,
+    pub mode: JsonlMode,
+}
+
+impl Default for JsonlOptions {
+    fn default() -> Self {
+        Self {
+            text_field: "text".to_string(),
+            language_field: Some("lang".to_string()),
+            default_language: "en".to_string(),
+            output_field: "sentences".to_string(),
+            mode: JsonlMode::default(),
+        }
+    }
+}
+
+/// Why a single JSONL record could not be segmented.
+#[derive(Debug)]
+pub enum JsonlError {
+    /// The line is not valid JSON.
+    InvalidJson(serde_json::Error),
+    /// The line is valid JSON but not an object.
+    NotAnObject,
+    /// The text field is absent or not a string.
+    MissingTextField(String),
+}
+
+impl fmt::Display for JsonlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonlError::InvalidJson(err) => write!(f, "invalid JSON: {err}"),
+            JsonlError::NotAnObject => write!(f, "record is not a JSON object"),
+            JsonlError::MissingTextField(field) => {
+                write!(f, "record has no string field `{field}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for JsonlError {}
+
+/// Counts from a `segment_jsonl` run. `errors` holds the 1-based line number
+/// and cause of every record that was skipped.
+#[derive(Debug, Default)]
+pub struct JsonlSummary {
+    pub records: usize,
+    pub errors: Vec<(usize, JsonlError)>,
+}
+
+/// Segment one JSONL line and return the record(s) to write back.
+/// `JsonlMode::Explode` yields one record per sentence, the other modes exactly one.
+///
+/// # Example
+///
+/// ```
+/// use sentencex::jsonl::{JsonlOptions, segment_record};
+///
+/// let line = r#"{"id":1,"lang":"fr","text":"Bonjour. Ça va ?"}"#;
+/// let records = segment_record(line, &JsonlOptions::default()).unwrap();
+///
+/// assert_eq!(records[0]["sentences"][1], "Ça va ?");
+/// ```
+pub fn segment_record(line: &str, options: &JsonlOptions) -> Result<Vec<Value>, JsonlError> {
+    let value: Value = serde_json::from_str(line).map_err(JsonlError::InvalidJson)?;
+    let Value::Object(mut record) = value else {
+        return Err(JsonlError::NotAnObject);
+    };
+
+    let Some(Value::String(text)) = record.get(&options.text_field) else {
+        return Err(JsonlError::MissingTextField(options.text_field.clone()));
+    };
+
+    let text = text.clone();
+    let language = options
+        .language_field
+        .as_ref()
+        .and_then(|field| record.get(field))
+        .and_then(Value::as_str)
+        .unwrap_or(&options.default_language);
+
+    let boundaries: Vec<SentenceBoundary> = get_sentence_boundaries(language, &text)
+        .into_iter()
+        .filter(|b| !b.is_paragraph_break && !b.text.trim().is_empty())
+        .collect();
+
+    match options.mode {
+        JsonlMode::Sentences => {
+            let sentences = boundaries
+                .iter()
+                .map(|b| Value::String(b.text.trim().to_string()))
+                .collect();
+
+            record.insert(options.output_field.clone(), Value::Array(sentences));
+            Ok(vec![Value::Object(record)])
+        }
+
+        JsonlMode::Boundaries => {
+            let boundaries =
+                serde_json::to_value(&boundaries).expect("SentenceBoundary always serializes");
+
+            record.insert(options.output_field.clone(), boundaries);
+            Ok(vec![Value::Object(record)])
+        }
+
+        JsonlMode::Explode if boundaries.is_empty() => Ok(vec![Value::Object(explode_record(
+            &record, options, 0, "", 0,
+        ))]),
+
+        JsonlMode::Explode => Ok(boundaries
+            .iter()
+            .enumerate()
+            .map(|(i, b)| Value::Object(explode_record(&record, options, i, b.text, b.start_byte)))
+            .collect()),
+    }
+}
+
+/// `record` with `text_field` replaced by `sentence_text` (trimmed) and its index and
+/// offsets added; `start_byte` is where the untrimmed `sentence_text` starts.
+fn explode_record(
+    record: &Map<String, Value>,
+    options: &JsonlOptions,
+    index: usize,
+    sentence_text: &str,
+    start_byte: usize,
+) -> Map<String, Value> {
+    let mut sentence = record.clone();
+
+    let text = sentence_text.trim_start();
+    let start_byte = start_byte + sentence_text.len() - text.len();
+    let text = text.trim_end();
+
+    sentence.insert(options.text_field.clone(), Value::String(text.to_string()));
+    sentence.insert("sentence_index".to_string(), index.into());
+    sentence.insert("start_byte".to_string(), start_byte.into());
+    sentence.insert("end_byte".to_string(), (start_byte + text.len()).into());
+
+    sentence
+}
+
+/// Segment every JSONL record read from `input` and write the results to
+/// `output`, one JSON object per line. Blank lines are skipped. A record that
+/// fails to parse is written as `{"error": …, "line": …}` in its place, so output
+/// lines stay aligned with input records, and listed in the returned summary;
+/// only I/O failures abort the run.
+pub fn segment_jsonl<R: BufRead, W: Write>(
+    input: R,
+    output: &mut W,
+    options: &JsonlOptions,
+) -> io::Result<JsonlSummary> {
+    let mut summary = JsonlSummary::default();
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match segment_record(&line, options) {
+            Ok(records) => {
+                for record in records {
+                    serde_json::to_writer(&mut *output, &record)?;
+                    writeln!(output)?;
+                }
+                summary.records += 1;
+            }
+            Err(err) => {
+                let record = serde_json::json!({ "error": err.to_string(), "line": i + 1 });
+                serde_json::to_writer(&mut *output, &record)?;
+                writeln!(output)?;
+                summary.errors.push((i + 1, err));
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(input: &str, options: &JsonlOptions) -> (Vec<Value>, JsonlSummary) {
+        let mut out = Vec::new();
+        let summary = segment_jsonl(input.as_bytes(), &mut out, options).unwrap();
+        let records = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        (records, summary)
+    }
+
+    #[test]
+    fn adds_sentences_and_keeps_field_order() {
+        let (records, _) = run(
+            r#"{"id":7,"text":"One. Two.","lang":"en"}"#,
+            &JsonlOptions::default(),
+        );
+
+        let keys: Vec<&String> = records[0].as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["id", "text", "lang", "sentences"]);
+        assert_eq!(records[0]["sentences"], serde_json::json!(["One.", "Two."]));
+    }
+
+    #[test]
+    fn uses_record_language_with_default_fallback() {
+        // German keeps `Am 3. Oktober` together; English does not know the month.
+        let input = concat!(
+            "{\"lang\":\"de\",\"text\":\"Am 3. Oktober kam er.\"}\n",
+            "{\"text\":\"Am 3. Oktober kam er.\"}\n",
+        );
+        let (records, _) = run(input, &JsonlOptions::default());
+
+        assert_eq!(
+            records[0]["sentences"],
+            serde_json::json!(["Am 3. Oktober kam er."])
+        );
+        assert_eq!(
+            records[1]["sentences"],
+            serde_json::json!(["Am 3.", "Oktober kam er."])
+        );
+    }
+
+    #[test]
+    fn explode_emits_one_record_per_sentence() {
+        let options = JsonlOptions {
+            mode: JsonlMode::Explode,
+            ..JsonlOptions::default()
+        };
+
+        let (records, summary) = run(
+            "{\"id\":1,\"text\":\"One two. Three four.\\n\\nFive six.\"}\n",
+            &options,
+        );
+
+        assert_eq!(summary.records, 1);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2]["id"], 1);
+        assert_eq!(records[0]["text"], "One two.");
+        assert_eq!(records[0]["end_byte"], 8);
+        assert_eq!(records[2]["text"], "Five six.");
+        assert_eq!(records[2]["sentence_index"], 2);
+        assert_eq!(records[2]["start_byte"], 22);
+        assert_eq!(records[2]["end_byte"], 31);
+    }
+
+    #[test]
+    fn explode_keeps_records_without_sentences() {
+        let options = JsonlOptions {
+            mode: JsonlMode::Explode,
+            ..JsonlOptions::default()
+        };
+
+        let (records, summary) = run(
+            "{\"id\":1,\"text\":\"  \"}\n{\"id\":2,\"text\":\"One.\"}\n",
+            &options,
+        );
+
+        assert_eq!(summary.records, 2);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["id"], 1);
+        assert_eq!(records[0]["text"], "");
+        assert_eq!(records[0]["sentence_index"], 0);
+        assert_eq!(records[0]["end_byte"], 0);
+        assert_eq!(records[1]["text"], "One.");
+    }
+
+    #[test]
+    fn boundaries_mode_writes_offsets() {
+        let options = JsonlOptions {
+            mode: JsonlMode::Boundaries,
+            output_field: "segments".to_string(),
+            ..JsonlOptions::default()
+        };
+
+        let (records, _) = run(r#"{"text":"One. Two."}"#, &options);
+        assert_eq!(records[0]["segments"][1]["start_byte"], 5);
+        assert_eq!(records[0]["segments"][1]["boundary_symbol"], ".");
+    }
+
+    #[test]
+    fn bad_records_are_reported_in_place() {
+        let input = "{\"text\":\"Fine.\"}\nnot json\n[1]\n{\"body\":\"x\"}\n\n";
+        let (records, summary) = run(input, &JsonlOptions::default());
+
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0]["sentences"], serde_json::json!(["Fine."]));
+        assert_eq!(records[2]["line"], 3);
+        assert_eq!(records[2]["error"], "record is not a JSON object");
+        assert_eq!(summary.records, 1);
+
+        let lines: Vec<usize> = summary.errors.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, [2, 3, 4]);
+        assert!(matches!(summary.errors[1].1, JsonlError::NotAnObject));
+    }
+}
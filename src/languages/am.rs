@@ -10,6 +10,10 @@ static AMHARIC_ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/am.txt")]));
 
 impl Language for Amharic {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("am")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &AMHARIC_ABBREVIATIONS
     }
@@ -14,6 +14,10 @@ static ARABIC_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
 });
 
 impl Language for Arabic {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("ar")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &ARABIC_ABBREVIATIONS
     }
@@ -2,17 +2,32 @@ use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 use super::Language;
-use super::parse_lowercase_word_list;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list};
 
 #[derive(Debug, Clone)]
 pub struct Bulgarian {}
 static BULGARIAN_ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/bg.txt")]));
 
+static BULGARIAN_MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/bg.txt"
+    )))
+});
+
 impl Language for Bulgarian {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &BULGARIAN_ABBREVIATIONS
     }
+
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &BULGARIAN_MARKERS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("bg")
+    }
 }
 
 #[cfg(test)]
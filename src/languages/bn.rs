@@ -14,6 +14,10 @@ static BENGALI_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
 });
 
 impl Language for Bengali {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("bn")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &BENGALI_ABBREVIATIONS
     }
@@ -1,8 +1,8 @@
-use regex::Regex;
 use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 use super::Language;
+use super::language::continues_after_nonword;
 use super::parse_lowercase_word_list;
 
 #[derive(Debug, Clone)]
@@ -15,9 +15,16 @@ impl Language for Catalan {
         &CATALAN_ABBREVIATIONS
     }
 
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("ca")
+    }
+
+    fn uses_inverted_marks(&self) -> bool {
+        true
+    }
+
     fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        let regex = Regex::new(r"^\W*[0-9a-z]").unwrap();
-        regex.is_match(text_after_boundary)
+        continues_after_nonword(text_after_boundary)
     }
 }
 
@@ -0,0 +1,180 @@
+// Month, weekday and era names from the CLDR Gregorian calendar, bundled per
+// language in `calendar/<code>.txt` and generated by `scripts/calendar_from_cldr.py`.
+// They keep dates together where a period sits inside them: after a day number
+// (`15. Mai`, `7. apríla`), after an abbreviated month or weekday before a day
+// number or year (`3 janv. 2020`, `So. 3. Mai`) and between the parts of an era
+// marker (`44 v. Chr.`, `52 av. J.-C.`).
+use std::sync::LazyLock;
+
+use rustc_hash::FxHashSet;
+
+/// Calendar names of one language. See `CalendarNames::parse` for the file format.
+#[derive(Debug, Default)]
+pub struct CalendarNames {
+    /// Every month form, lowercased, without a trailing period.
+    months: FxHashSet<String>,
+    /// Month and weekday forms written with a trailing period (`janv.`, `So.`),
+    /// without the period, as written and capitalised.
+    abbreviations: FxHashSet<String>,
+    /// Era markers as written (`v. Chr.`, `до н. э.`).
+    eras: Vec<&'static str>,
+}
+
+/// `word` without trailing punctuation.
+fn trim(word: &str) -> &str {
+    word.trim_end_matches(['.', ',', ';', ':'])
+}
+
+/// `word` with its first letter uppercased (`janv` → `Janv`).
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Calendar table for every bundled language code.
+macro_rules! bundled_calendars {
+    ($code:expr, [$($name:literal),* $(,)?]) => {
+        match $code {
+            $($name => {
+                static NAMES: LazyLock<CalendarNames> = LazyLock::new(|| {
+                    CalendarNames::parse(include_str!(concat!("./calendar/", $name, ".txt")))
+                });
+                &NAMES
+            })*
+            _ => CalendarNames::empty(),
+        }
+    };
+}
+
+impl CalendarNames {
+    pub fn empty() -> &'static Self {
+        static EMPTY: LazyLock<CalendarNames> = LazyLock::new(CalendarNames::default);
+
+        &EMPTY
+    }
+
+    /// The bundled table for `code`, or an empty one when none is bundled.
+    pub fn for_code(code: &str) -> &'static Self {
+        bundled_calendars!(
+            code,
+            [
+                "am", "ar", "bg", "bn", "ca", "da", "de", "el", "en", "es", "fa", "fi", "fr", "gl",
+                "gu", "he", "hi", "hy", "it", "ja", "kk", "km", "kn", "ko", "lo", "ml", "mr", "my",
+                "nl", "pa", "pl", "pt", "ru", "sk", "ta", "te", "th", "tr", "uk", "ur", "zh",
+            ]
+        )
+    }
+
+    /// Parse a bundled calendar file: `kind | form | form ...` per line, with
+    /// `kind` one of `month` (wide and abbreviated, format and stand-alone forms,
+    /// other grammatical cases), `weekday` or `era` (one marker per form, spaced
+    /// as usually written). Blank lines and `//` comments are skipped.
+    ///
+    /// Month forms match in any case. Forms ending in `.` are abbreviations and
+    /// match as written or capitalised (`Wed.`, `janv.` or `Janv.`, but not `wed.`).
+    pub fn parse(source: &'static str) -> Self {
+        let mut names = Self::default();
+
+        for raw in source.lines() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            let mut parts = line.split('|').map(str::trim);
+            let kind = parts.next().expect("split yields at least one element");
+            let forms = parts.filter(|form| !form.is_empty());
+
+            match kind {
+                "month" | "weekday" => {
+                    for form in forms {
+                        if form.ends_with('.') {
+                            names.abbreviations.insert(trim(form).to_string());
+                            names.abbreviations.insert(capitalize(trim(form)));
+                        }
+                        if kind == "month" {
+                            names.months.insert(trim(form).to_lowercase());
+                        }
+                    }
+                }
+                "era" => names.eras.extend(forms),
+                other => panic!("calendar line has unknown kind `{other}`: {raw:?}"),
+            }
+        }
+
+        names
+    }
+
+    /// Whether `word` is a month name or abbreviation, in any case and with or
+    /// without a trailing period. `lowercase` is the language's case folding
+    /// (`MAYIS` → `mayıs` in Turkish).
+    pub fn is_month(&self, word: &str, lowercase: impl Fn(&str) -> String) -> bool {
+        !self.months.is_empty() && self.months.contains(&lowercase(trim(word)))
+    }
+
+    /// True when the `.` after `head` belongs to a date: it ends an abbreviated
+    /// month or weekday followed by a day number or year, or it sits inside an
+    /// era marker.
+    pub(crate) fn period_continues_date(
+        &self,
+        head: &str,
+        last_word: &str,
+        next_word_approx: &str,
+    ) -> bool {
+        let next = next_word_approx.trim_start();
+
+        if next.starts_with(|c: char| c.is_ascii_digit())
+            && self.abbreviations.contains(trim(last_word))
+        {
+            return true;
+        }
+
+        self.eras.iter().any(|era| splits_era(era, head, next))
+    }
+}
+
+/// True when `head` ends with the part of `era` before one of its inner periods
+/// and `next` starts with the part after it (`v` | `Chr.` for `v. Chr.`).
+fn splits_era(era: &str, head: &str, next: &str) -> bool {
+    let inner = era.strip_suffix('.').unwrap_or(era);
+
+    inner.match_indices('.').any(|(idx, _)| {
+        let before = &era[..idx];
+        let after = era[idx + 1..].trim_start();
+
+        head.strip_suffix(before)
+            .is_some_and(|rest| !rest.chars().next_back().is_some_and(char::is_alphanumeric))
+            && next.starts_with(after)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_forms_by_kind() {
+        let names = CalendarNames::parse(
+            "// comment\nmonth | Januar | Jan.\nweekday | Sonntag | So.\nera | v. Chr.\n",
+        );
+
+        assert!(names.is_month("januar", str::to_lowercase));
+        assert!(names.is_month("Jan.", str::to_lowercase));
+        assert!(!names.is_month("Sonntag", str::to_lowercase));
+        assert!(names.period_continues_date("am So", "So", " 3. Mai"));
+        assert!(!names.period_continues_date("am so", "so", " 3. Mai"));
+        assert!(!names.period_continues_date("am So", "So", " Mai"));
+        assert!(names.period_continues_date("44 v", "v", " Chr. wurde"));
+        assert!(!names.period_continues_date("Er ging nach Kiew", "Kiew", " Chr."));
+    }
+
+    #[test]
+    fn bundles_every_supported_language() {
+        for code in crate::SUPPORTED_LANGUAGES {
+            let names = CalendarNames::for_code(code);
+            assert!(names.months.len() >= 12, "no calendar names for {code}");
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// Technical-text mode. Developer documentation is full of code tokens whose periods, `?` and
+// `!` never end a sentence: `foo.bar()`, `std::io::Read`, `obj.method().then()`,
+// `$HOME/.config`, `` `x. Y` ``. `TechnicalText` wraps any language and skips these tokens
+// like quotes. It is opt-in, since prose like `him.Go away` or `and/or` reads differently.
+// Trailing punctuation stays outside a token, so `Call obj.method(). Then …` still splits.
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::language::{Language, SkippableRange, SkippableRangeType};
+use super::technical::trim_trailing_punctuation;
+use super::wrapper::forward_language;
+
+/// A fenced block (```` ```…``` ````), a double-backtick span or a single-backtick span on one
+/// line.
+static BACKTICK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s:```.*?```)|``[^`]+``|`[^`\n]+`").unwrap());
+
+/// A call or member chain (`foo.bar()`, `obj->next(a, b).len()`, `self.item_count`), a qualified name
+/// (`std::io::Read`, `Vec::<u8>::new`) or a shell variable path (`$HOME/.config`,
+/// `${XDG_DATA_HOME}/app`, `~/.ssh/config`). Member chains without a call are vetted by
+/// `is_code_token`.
+static CODE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?-u:\b)[A-Za-z_][A-Za-z0-9_]*(?:<[A-Za-z0-9_, ]*>)?",
+        r"(?:(?:::|\.|->)[A-Za-z_][A-Za-z0-9_]*(?:<[A-Za-z0-9_, ]*>)?|\([^()\n]*\))+",
+        r"|(?:\$\{?[A-Za-z_][A-Za-z0-9_]*\}?|~)(?:/[A-Za-z0-9_.+@%-]+)+/?",
+    ))
+    .unwrap()
+});
+
+/// Whether a matched chain reads as code rather than prose: it has a call, a `::` or `->`
+/// separator, or a member with an underscore or an inner capital (`self.item_count`,
+/// `document.getElementById`). `him.Go` and `U.S` do not qualify.
+fn is_code_token(token: &str) -> bool {
+    if token.starts_with(['$', '~']) {
+        return true;
+    }
+
+    if token.contains("::") || token.contains("->") {
+        return true;
+    }
+
+    let Some((name, rest)) = token.split_once('.') else {
+        // A bare call (`print(x)`) is left to the parentheses range.
+        return false;
+    };
+
+    rest.ends_with(')')
+        || rest.contains('(')
+        || name.contains('_')
+        || rest.split('.').any(|member| {
+            member.contains('_')
+                || member
+                    .as_bytes()
+                    .windows(2)
+                    .any(|w| w[0].is_ascii_lowercase() && w[1].is_ascii_uppercase())
+        })
+}
+
+/// Push a skippable range for every backtick span, call or member chain, qualified name and
+/// shell variable path in `text`.
+pub(crate) fn collect_code_ranges(text: &str, out: &mut Vec<SkippableRange>) {
+    let first = out.len();
+
+    if memchr::memchr(b'`', text.as_bytes()).is_some() {
+        for mat in BACKTICK_REGEX.find_iter(text) {
+            out.push(SkippableRange::new(
+                mat.start(),
+                mat.end(),
+                SkippableRangeType::Code,
+            ));
+        }
+    }
+
+    let spans = out.len();
+    for mat in CODE_REGEX.find_iter(text) {
+        if out[first..spans]
+            .iter()
+            .any(|r| r.start <= mat.start() && mat.start() < r.end)
+        {
+            continue;
+        }
+
+        let trimmed = trim_trailing_punctuation(mat.as_str());
+        if is_code_token(trimmed) {
+            out.push(SkippableRange::new(
+                mat.start(),
+                mat.start() + trimmed.len(),
+                SkippableRangeType::Code,
+            ));
+        }
+    }
+}
+
+/// Wraps a language to segment technical text: on top of the language's own skippable ranges,
+/// code tokens are skipped too (see the module comment). Every other decision is the wrapped
+/// language's.
+///
+/// ```
+/// use sentencex::{language_factory, segment_with_language};
+/// use sentencex::languages::TechnicalText;
+///
+/// let language = TechnicalText::new(language_factory("en"));
+/// assert_eq!(
+///     segment_with_language(&language, "Call Config.Load() first. Then run it."),
+///     vec!["Call Config.Load() first. ", "Then run it."]
+/// );
+/// ```
+pub struct TechnicalText {
+    inner: Box<dyn Language>,
+}
+
+impl TechnicalText {
+    pub fn new(inner: Box<dyn Language>) -> Self {
+        Self { inner }
+    }
+}
+
+impl Language for TechnicalText {
+    forward_language!();
+
+    fn get_sentence_break_regex(&self) -> &'static Regex {
+        self.inner.get_sentence_break_regex()
+    }
+
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        self.inner.find_boundary(text, start, end)
+    }
+
+    fn get_skippable_ranges(&self, text: &str, out: &mut Vec<SkippableRange>) {
+        self.inner.get_skippable_ranges(text, out);
+
+        let first = out.len();
+        collect_code_ranges(text, out);
+        if out.len() > first {
+            out.sort_by_key(|r| r.start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::languages::tests::run_language_tests;
+    use crate::languages::{English, German};
+
+    fn ranges(text: &str) -> Vec<&str> {
+        let mut out = Vec::new();
+        collect_code_ranges(text, &mut out);
+        out.iter().map(|r| &text[r.start..r.end]).collect()
+    }
+
+    #[test]
+    fn recognises_code_tokens() {
+        assert_eq!(ranges("Call foo.bar()."), vec!["foo.bar()"]);
+        assert_eq!(ranges("Use std::io::Read!"), vec!["std::io::Read"]);
+        assert_eq!(
+            ranges("Then obj.method(a, b).Next() runs."),
+            vec!["obj.method(a, b).Next()"]
+        );
+        assert_eq!(ranges("Read self.item_count."), vec!["self.item_count"]);
+        assert_eq!(ranges("Edit $HOME/.config."), vec!["$HOME/.config"]);
+        assert_eq!(ranges("Run `make. Install` now."), vec!["`make. Install`"]);
+        assert!(ranges("I saw him.Go away. He said print(it) and/or U.S. Army.").is_empty());
+    }
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(
+            TechnicalText::new(Box::new(English {})),
+            "tests/technical.txt",
+        );
+    }
+
+    #[test]
+    fn keeps_the_wrapped_language() {
+        let language = TechnicalText::new(Box::new(German {}));
+        assert_eq!(
+            crate::segment_with_language(
+                &language,
+                "Am 3. Mai rief er Foo.Bar() auf. Dann ging er."
+            ),
+            vec!["Am 3. Mai rief er Foo.Bar() auf. ", "Dann ging er."]
+        );
+    }
+}
@@ -3,7 +3,10 @@ use std::sync::LazyLock;
 
 use super::Language;
 use super::language::continues_after_boundary;
-use super::parse_lowercase_word_list;
+use super::leading_markers::LeadingMarkerTable;
+use super::ordinals::OrdinalPolicy;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list, parse_word_list};
 
 #[derive(Debug, Clone)]
 pub struct German {}
@@ -15,28 +18,70 @@ static GERMAN_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
     ])
 });
 
-const MONTHS: [&str; 12] = [
-    "Januar",
-    "Februar",
-    "März",
-    "April",
-    "Mai",
-    "Juni",
-    "Juli",
-    "August",
-    "September",
-    "Oktober",
-    "November",
-    "Dezember",
-];
+static GERMAN_SENTENCE_STARTERS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_word_list([include_str!("./starters/de.txt")]));
+
+static GERMAN_FRONTING_WORDS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_lowercase_word_list([include_str!("./fronting/de.txt")]));
+
+static GERMAN_MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/de.txt"
+    )))
+});
+
+static GERMAN_LEADING_MARKERS: LazyLock<LeadingMarkerTable> =
+    LazyLock::new(|| LeadingMarkerTable::parse(include_str!("./leading_markers/de.txt")));
+
+/// Nouns are capitalised, so `der 2. Weltkrieg` and `am 3. Oktober` continue.
+/// A capitalised follower only continues after an article or a contracted
+/// preposition: `Sie belegte Platz 3. Trainer Müller war zufrieden.` splits.
+/// Articles and pronouns cannot follow an ordinal and start a new sentence.
+static GERMAN_ORDINALS: OrdinalPolicy = OrdinalPolicy {
+    max_digits: 3,
+    capitalized_followers: true,
+    capitalized_after: &[
+        "der", "die", "das", "den", "dem", "des", "ein", "eine", "einen", "einem", "einer",
+        "eines", "am", "im", "ins", "zum", "zur", "vom", "beim",
+    ],
+    breaking_followers: &[
+        "Der", "Die", "Das", "Den", "Dem", "Des", "Ein", "Eine", "Einen", "Einem", "Einer",
+        "Eines", "Er", "Sie", "Es", "Wir", "Ich", "Ihr", "Man", "Dies", "Diese", "Dieser",
+        "Dieses", "Dann", "Doch", "Aber", "Auch", "So", "Da", "Denn", "Im", "In", "Am",
+    ],
+};
 
 impl Language for German {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &GERMAN_ABBREVIATIONS
     }
 
+    fn get_sentence_starters(&self) -> &FxHashSet<String> {
+        &GERMAN_SENTENCE_STARTERS
+    }
+
+    fn get_fronting_words(&self) -> &FxHashSet<String> {
+        &GERMAN_FRONTING_WORDS
+    }
+
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &GERMAN_MARKERS
+    }
+
+    fn get_leading_markers(&self) -> &'static LeadingMarkerTable {
+        &GERMAN_LEADING_MARKERS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("de")
+    }
+
+    fn get_ordinal_policy(&self) -> Option<&'static OrdinalPolicy> {
+        Some(&GERMAN_ORDINALS)
+    }
+
     fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        continues_after_boundary(text_after_boundary, &MONTHS)
+        continues_after_boundary(text_after_boundary, self)
     }
 }
 
@@ -0,0 +1,75 @@
+// Dialogue dashes. Russian, Ukrainian, Spanish and French fiction opens each utterance with a
+// dash instead of quotes: `— Ты придёшь? — спросил он. — Да.` After a terminator, a dash
+// followed by a lowercase word is the attribution clause and continues the sentence
+// (`— спросил он`), while a dash followed by a capital or an opener is the next reply
+// (`— Да.`, `—¿Vienes?`). A line that starts with a reply dash is a new turn even without a
+// terminator before it.
+
+/// Dashes that open an utterance: em dash, en dash (common in typed text) and horizontal bar.
+pub(crate) const DIALOGUE_DASHES: &[char] = &['—', '–', '―'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DialogueTurn {
+    /// `— спросил он`, `—preguntó él`: the utterance before the dash goes on.
+    Attribution,
+    /// `— Да`, `—¿Vienes?`, `— « Oui »`: a new utterance starts at the dash.
+    Reply,
+}
+
+/// Classify `text` (after leading whitespace) when it starts with one of `dashes`.
+pub(crate) fn classify_dialogue_dash(text: &str, dashes: &[char]) -> Option<DialogueTurn> {
+    let after_dash = text.trim_start().strip_prefix(dashes)?;
+    let first = after_dash.trim_start().chars().next()?;
+
+    if first.is_lowercase() {
+        Some(DialogueTurn::Attribution)
+    } else if first.is_uppercase() || matches!(first, '¿' | '¡' | '«' | '"' | '„') {
+        Some(DialogueTurn::Reply)
+    } else {
+        None
+    }
+}
+
+/// Push the start of every line after the first in `paragraph` that opens with a reply dash.
+/// Returns whether any was pushed.
+pub(crate) fn push_dialogue_turns(paragraph: &str, dashes: &[char], out: &mut Vec<usize>) -> bool {
+    let mut pushed = false;
+
+    for nl in memchr::memchr_iter(b'\n', paragraph.as_bytes()) {
+        let line = &paragraph[nl + 1..];
+        if classify_dialogue_dash(line, dashes) == Some(DialogueTurn::Reply) {
+            out.push(nl + 1);
+            pushed = true;
+        }
+    }
+
+    pushed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_what_follows_the_dash() {
+        use DialogueTurn::{Attribution, Reply};
+
+        let classify = |text| classify_dialogue_dash(text, DIALOGUE_DASHES);
+        assert_eq!(classify(" — спросил он."), Some(Attribution));
+        assert_eq!(classify(" —preguntó él."), Some(Attribution));
+        assert_eq!(classify(" — Да."), Some(Reply));
+        assert_eq!(classify(" —¿Vienes?"), Some(Reply));
+        assert_eq!(classify(" – Так."), Some(Reply));
+        assert_eq!(classify(" — 1990"), None);
+        assert_eq!(classify(" Да."), None);
+    }
+
+    #[test]
+    fn finds_turns_at_line_starts() {
+        let mut out = Vec::new();
+        let paragraph = "— Привет\n— Как дела\n— и всё\nДа";
+
+        assert!(push_dialogue_turns(paragraph, DIALOGUE_DASHES, &mut out));
+        assert_eq!(out, vec![paragraph.find("— Как").unwrap()]);
+    }
+}
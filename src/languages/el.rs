@@ -31,6 +31,10 @@ impl Language for Greek {
         &GREEK_ABBREVIATIONS
     }
 
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("el")
+    }
+
     fn get_sentence_break_regex(&self) -> &'static Regex {
         &GREEK_SENTENCE_BREAK_REGEX
     }
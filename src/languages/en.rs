@@ -1,4 +1,5 @@
 use super::Language;
+use super::leading_markers::LeadingMarkerTable;
 use super::trailing_markers::MarkerTable;
 use super::{parse_lowercase_word_list, parse_markers_list, parse_word_list};
 use regex::Regex;
@@ -23,6 +24,9 @@ static ENGLISH_MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
     )))
 });
 
+static ENGLISH_LEADING_MARKERS: LazyLock<LeadingMarkerTable> =
+    LazyLock::new(|| LeadingMarkerTable::parse(include_str!("./leading_markers/en.txt")));
+
 // English `I` is the one capital that case cannot distinguish from a sentence
 // start, so after an ellipsis run `... I'm` reads as continuation.
 static ENGLISH_ELLIPSIS_I_REGEX: LazyLock<Regex> =
@@ -33,6 +37,10 @@ impl Language for English {
         &ENGLISH_ABBREVIATIONS
     }
 
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("en")
+    }
+
     fn get_sentence_starters(&self) -> &FxHashSet<String> {
         &ENGLISH_SENTENCE_STARTERS
     }
@@ -45,8 +53,12 @@ impl Language for English {
         &ENGLISH_MARKERS
     }
 
+    fn get_leading_markers(&self) -> &'static LeadingMarkerTable {
+        &ENGLISH_LEADING_MARKERS
+    }
+
     fn is_ellipsis_continuation(&self, text_after_run: &str) -> bool {
-        super::language::ELLIPSIS_CONTINUE_REGEX.is_match(text_after_run)
+        super::language::ellipsis_continues(text_after_run)
             || ENGLISH_ELLIPSIS_I_REGEX.is_match(text_after_run)
     }
 }
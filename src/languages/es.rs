@@ -2,17 +2,63 @@ use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 use super::Language;
-use super::parse_lowercase_word_list;
+use super::dialogue::DIALOGUE_DASHES;
+use super::leading_markers::LeadingMarkerTable;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list, parse_word_list};
 
 #[derive(Debug, Clone)]
 pub struct Spanish {}
 static SPANISH_ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/es.txt")]));
 
+static SPANISH_SENTENCE_STARTERS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_word_list([include_str!("./starters/es.txt")]));
+
+static SPANISH_FRONTING_WORDS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_lowercase_word_list([include_str!("./fronting/es.txt")]));
+
+static SPANISH_MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/es.txt"
+    )))
+});
+
+static SPANISH_LEADING_MARKERS: LazyLock<LeadingMarkerTable> =
+    LazyLock::new(|| LeadingMarkerTable::parse(include_str!("./leading_markers/es.txt")));
+
 impl Language for Spanish {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &SPANISH_ABBREVIATIONS
     }
+
+    fn get_sentence_starters(&self) -> &FxHashSet<String> {
+        &SPANISH_SENTENCE_STARTERS
+    }
+
+    fn get_fronting_words(&self) -> &FxHashSet<String> {
+        &SPANISH_FRONTING_WORDS
+    }
+
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &SPANISH_MARKERS
+    }
+
+    fn get_leading_markers(&self) -> &'static LeadingMarkerTable {
+        &SPANISH_LEADING_MARKERS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("es")
+    }
+
+    fn get_dialogue_dashes(&self) -> &'static [char] {
+        DIALOGUE_DASHES
+    }
+
+    fn uses_inverted_marks(&self) -> bool {
+        true
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,79 @@
+use rustc_hash::FxHashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::language::{
+    abbreviation_set_contains, default_continue_in_next_word, default_find_boundary,
+    sentence_break_regex, starts_with_arabic_indic_digit,
+};
+use super::{Language, parse_lowercase_word_list};
+
+#[derive(Debug, Clone)]
+pub struct Persian {}
+
+/// Zero-width non-joiner, written inside Persian words (`می‌شود`).
+const ZWNJ: char = '\u{200C}';
+
+static PERSIAN_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
+    parse_lowercase_word_list([
+        include_str!("./abbrev/fa.txt"),
+        include_str!("./abbrev/en.txt"),
+    ])
+});
+
+// The default pattern plus the Arabic semicolon `؛`.
+static PERSIAN_SENTENCE_BREAK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| sentence_break_regex(&['؛']));
+
+impl Language for Persian {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("fa")
+    }
+
+    fn get_abbreviations(&self) -> &FxHashSet<String> {
+        &PERSIAN_ABBREVIATIONS
+    }
+
+    fn get_sentence_break_regex(&self) -> &'static Regex {
+        &PERSIAN_SENTENCE_BREAK_REGEX
+    }
+
+    /// Abbreviations are looked up whole, dots included (`ق.م`, `ه.ش`), and
+    /// without ZWNJ so either spelling matches the list. A dot between two
+    /// single letters is inside such an abbreviation.
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        if &text[start..end] == "." {
+            let head = &text[..start];
+            if self.get_last_word(head).chars().count() == 1
+                && text[end..].chars().next().is_some_and(char::is_alphabetic)
+            {
+                return None;
+            }
+
+            let word = self.get_last_word_full(head).replace(ZWNJ, "");
+            if !word.is_empty() && abbreviation_set_contains(self.get_abbreviations(), &word) {
+                return None;
+            }
+        }
+
+        default_find_boundary(self, text, start, end)
+    }
+
+    fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
+        starts_with_arabic_indic_digit(text_after_boundary)
+            || default_continue_in_next_word(text_after_boundary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Persian {}, "tests/fa.txt");
+    }
+}
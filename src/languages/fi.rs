@@ -11,28 +11,17 @@ pub struct Finnish {}
 static FINNISH_ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/fi.txt")]));
 
-const MONTHS: [&str; 12] = [
-    "tammikuu",
-    "helmikuu",
-    "maaliskuu",
-    "huhtikuu",
-    "toukokuu",
-    "kesäkuu",
-    "heinäkuu",
-    "elokuu",
-    "syyskuu",
-    "lokakuu",
-    "marraskuu",
-    "joulukuu",
-];
-
 impl Language for Finnish {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &FINNISH_ABBREVIATIONS
     }
 
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("fi")
+    }
+
     fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        continues_after_boundary(text_after_boundary, &MONTHS)
+        continues_after_boundary(text_after_boundary, self)
     }
 }
 
@@ -2,17 +2,59 @@ use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 use super::Language;
-use super::parse_lowercase_word_list;
+use super::dialogue::DIALOGUE_DASHES;
+use super::leading_markers::LeadingMarkerTable;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list, parse_word_list};
 
 #[derive(Debug, Clone)]
 pub struct French {}
 static FRENCH_ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/fr.txt")]));
 
+static FRENCH_SENTENCE_STARTERS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_word_list([include_str!("./starters/fr.txt")]));
+
+static FRENCH_FRONTING_WORDS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_lowercase_word_list([include_str!("./fronting/fr.txt")]));
+
+static FRENCH_MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/fr.txt"
+    )))
+});
+
+static FRENCH_LEADING_MARKERS: LazyLock<LeadingMarkerTable> =
+    LazyLock::new(|| LeadingMarkerTable::parse(include_str!("./leading_markers/fr.txt")));
+
 impl Language for French {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &FRENCH_ABBREVIATIONS
     }
+
+    fn get_sentence_starters(&self) -> &FxHashSet<String> {
+        &FRENCH_SENTENCE_STARTERS
+    }
+
+    fn get_fronting_words(&self) -> &FxHashSet<String> {
+        &FRENCH_FRONTING_WORDS
+    }
+
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &FRENCH_MARKERS
+    }
+
+    fn get_leading_markers(&self) -> &'static LeadingMarkerTable {
+        &FRENCH_LEADING_MARKERS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("fr")
+    }
+
+    fn get_dialogue_dashes(&self) -> &'static [char] {
+        DIALOGUE_DASHES
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,34 @@
+use rustc_hash::FxHashSet;
+
+use super::{Language, Portuguese};
+
+/// Galician shares Portuguese abbreviations but, like Spanish, may open questions
+/// and exclamations with `¿` and `¡`.
+#[derive(Debug, Clone)]
+pub struct Galician {}
+
+impl Language for Galician {
+    fn get_abbreviations(&self) -> &FxHashSet<String> {
+        Portuguese {}.get_abbreviations()
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("gl")
+    }
+
+    fn uses_inverted_marks(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Galician {}, "tests/gl.txt");
+    }
+}
@@ -14,6 +14,10 @@ static GUJARATI_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
     ])
 });
 impl Language for Gujarati {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("gu")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &GUJARATI_ABBREVIATIONS
     }
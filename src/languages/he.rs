@@ -0,0 +1,163 @@
+use rustc_hash::FxHashSet;
+use std::borrow::Cow;
+use std::sync::LazyLock;
+
+use super::language::{
+    SkippableRange, abbreviation_set_contains, collect_skippable_ranges, default_find_boundary,
+};
+use super::{Language, parse_lowercase_word_list};
+
+#[derive(Debug, Clone)]
+pub struct Hebrew {}
+
+static HEBREW_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
+    parse_lowercase_word_list([
+        include_str!("./abbrev/he.txt"),
+        include_str!("./abbrev/en.txt"),
+    ])
+});
+
+/// Hebrew punctuation gershayim (`״`) and geresh (`׳`), usually typed as ASCII `"` and `'`.
+const GERSHAYIM: char = '\u{05F4}';
+const GERESH: char = '\u{05F3}';
+
+/// Maqaf, the Hebrew hyphen joining words (`בית־הספר`).
+const MAQAF: char = '\u{05BE}';
+
+/// Directional marks that RTL text carries around punctuation.
+const DIRECTION_MARKS: [char; 3] = ['\u{200E}', '\u{200F}', '\u{061C}'];
+
+fn is_hebrew_letter(c: char) -> bool {
+    matches!(c, '\u{05D0}'..='\u{05EA}' | '\u{05F0}'..='\u{05F2}')
+}
+
+/// True when the `"` at `idx` is a gershayim between two Hebrew letters (`ד"ר`)
+/// rather than a quotation mark.
+fn is_intra_word_gershayim(text: &str, idx: usize) -> bool {
+    text[..idx]
+        .chars()
+        .next_back()
+        .is_some_and(is_hebrew_letter)
+        && text[idx + 1..].chars().next().is_some_and(is_hebrew_letter)
+}
+
+/// True when the `'` at `idx` opens a single-quoted span: it starts a token and
+/// a letter follows it (`'שלום`).
+fn opens_single_quote(text: &str, idx: usize) -> bool {
+    text[..idx]
+        .chars()
+        .next_back()
+        .is_none_or(|c| c.is_whitespace() || matches!(c, '(' | '[' | '-' | '—' | ':'))
+        && text[idx + 1..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphabetic)
+}
+
+/// `text` with every intra-word `"`/`'` replaced by a same-width placeholder, so
+/// quote pairing never sees them. A `'` after a Hebrew letter is a geresh when a
+/// Hebrew letter follows it (`צ'יפס`) or when no single-quoted span is open
+/// (`גב' לוי`); otherwise it closes the span (`'שלום לכם'`). Borrowed when there
+/// is nothing to replace.
+fn mask_intra_word_marks(text: &str) -> Cow<'_, str> {
+    let mut marks = Vec::new();
+    let mut quote_open = false;
+
+    for idx in memchr::memchr2_iter(b'"', b'\'', text.as_bytes()) {
+        if text.as_bytes()[idx] == b'"' {
+            if is_intra_word_gershayim(text, idx) {
+                marks.push(idx);
+            }
+            continue;
+        }
+
+        if !text[..idx]
+            .chars()
+            .next_back()
+            .is_some_and(is_hebrew_letter)
+        {
+            quote_open |= opens_single_quote(text, idx);
+        } else if text[idx + 1..].chars().next().is_some_and(is_hebrew_letter) || !quote_open {
+            marks.push(idx);
+        } else {
+            quote_open = false;
+        }
+    }
+
+    if marks.is_empty() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut bytes = text.as_bytes().to_vec();
+    for idx in marks {
+        bytes[idx] = b'#';
+    }
+
+    Cow::Owned(String::from_utf8(bytes).expect("replacing ASCII with ASCII keeps UTF-8 valid"))
+}
+
+impl Language for Hebrew {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("he")
+    }
+
+    fn get_abbreviations(&self) -> &FxHashSet<String> {
+        &HEBREW_ABBREVIATIONS
+    }
+
+    fn is_abbreviation_for(&self, last_word: &str, separator: &str) -> bool {
+        if self.get_abbreviation_char() != separator || last_word.is_empty() {
+            return false;
+        }
+
+        let normalised: Cow<str> = if last_word.contains([GERSHAYIM, GERESH]) {
+            Cow::Owned(last_word.replace(GERSHAYIM, "\"").replace(GERESH, "'"))
+        } else {
+            Cow::Borrowed(last_word)
+        };
+
+        abbreviation_set_contains(self.get_abbreviations(), &normalised)
+    }
+
+    fn get_last_word<'a>(&self, text: &'a str) -> &'a str {
+        text.trim_end()
+            .rsplit(|c: char| c.is_whitespace() || c == '.' || c == '/' || c == MAQAF)
+            .next()
+            .expect("str::rsplit always yields at least one element")
+    }
+
+    /// Directional marks after a terminator belong to the sentence it ends.
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        let boundary = default_find_boundary(self, text, start, end)?;
+        let rest = text[boundary..]
+            .trim_start_matches(|c: char| c.is_whitespace() || DIRECTION_MARKS.contains(&c));
+
+        Some(text.len() - rest.len())
+    }
+
+    fn get_skippable_ranges(&self, text: &str, out: &mut Vec<SkippableRange>) {
+        collect_skippable_ranges(self, &mask_intra_word_marks(text), out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Hebrew {}, "tests/he.txt");
+    }
+
+    #[test]
+    fn intra_word_marks_are_masked() {
+        assert_eq!(mask_intra_word_marks(r#"ד"ר כהן"#), "ד#ר כהן");
+        assert_eq!(mask_intra_word_marks("גב' לוי"), "גב# לוי");
+        assert_eq!(mask_intra_word_marks("'שלום'"), "'שלום'");
+        assert_eq!(mask_intra_word_marks("'שלום לכם'"), "'שלום לכם'");
+        assert_eq!(mask_intra_word_marks("'צ'יפס טעים'"), "'צ#יפס טעים'");
+        assert_eq!(mask_intra_word_marks(r#"אמר: "כן.""#), r#"אמר: "כן.""#);
+    }
+}
@@ -14,6 +14,10 @@ static HINDI_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
 });
 
 impl Language for Hindi {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("hi")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &HINDI_ABBREVIATIONS
     }
@@ -20,6 +20,10 @@ static ARMENIAN_SENTENCE_BREAK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 });
 
 impl Language for Armenian {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("hy")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         English {}.get_abbreviations()
     }
@@ -0,0 +1,197 @@
+// Name initials across scripts. A cased script marks an initial with a single
+// capital (`J.`, `А.`, `Γ.`, `Ա.`). Indic scripts have no case: an initial is
+// one short syllable (`கெ.`, `पी.`) or a spelled-out Latin letter name
+// (`எம்.`, `എൽ.`, `एस.`), and only counts next to another one (`എ. കെ.`),
+// since one-syllable words end sentences too (`நீ போ.`). Georgian Mkhedruli is
+// caseless too, so any single letter reads as an initial (`ი. ჭავჭავაძე`).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Initial {
+    /// Rarely a whole word before `.`, so it is an initial wherever it appears:
+    /// a single Georgian letter.
+    Standalone,
+    /// Also a common word shape, so it needs a capitalised or initial neighbour:
+    /// a single capital (`J.`).
+    Contextual,
+    /// The shape of many short words, so it needs another initial, with its
+    /// period, beside it: an Indic syllable (`पी.`, but also `थी.`) or letter
+    /// name (`எம்.`, but also `ஆம்.` "yes").
+    Paired,
+}
+
+/// The Indic blocks share the ISCII layout, from Devanagari (U+0900) to
+/// Malayalam (U+0D7F). Each block is 128 code points.
+const INDIC_START: u32 = 0x0900;
+const INDIC_END: u32 = 0x0D7F;
+const MALAYALAM_BLOCK: u32 = 0x0D00;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IndicClass {
+    Vowel,
+    Consonant,
+    /// Vowel signs, nukta, anusvara, candrabindu and visarga.
+    Sign,
+    Virama,
+    /// Malayalam chillu, a consonant that carries no vowel (`ൽ`, `ൻ`).
+    Chillu,
+}
+
+fn indic_class(c: char) -> Option<IndicClass> {
+    let cp = c as u32;
+    if !(INDIC_START..=INDIC_END).contains(&cp) {
+        return None;
+    }
+
+    let block = cp & !0x7F;
+    let offset = cp & 0x7F;
+
+    if block == MALAYALAM_BLOCK && matches!(offset, 0x54..=0x56 | 0x7A..=0x7F) {
+        return Some(IndicClass::Chillu);
+    }
+
+    match offset {
+        0x4D => Some(IndicClass::Virama),
+        0x04..=0x14 | 0x60..=0x61 => Some(IndicClass::Vowel),
+        0x15..=0x39 | 0x58..=0x5F => Some(IndicClass::Consonant),
+        0x01..=0x03 | 0x3C | 0x3E..=0x4C | 0x4E..=0x4F | 0x55..=0x57 | 0x62..=0x63 => {
+            Some(IndicClass::Sign)
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn is_georgian_letter(c: char) -> bool {
+    matches!(c, '\u{10D0}'..='\u{10FA}')
+}
+
+/// Whether `c` is a vowel sign that spelled-out Latin letter names end in: `i`
+/// or `e`, short or long (`बी`, `பி`, `കെ`, `जे`). Other signs end common
+/// one-syllable words (`है`, `போ`, `రా`).
+fn is_letter_name_sign(c: char) -> bool {
+    matches!(c as u32 & 0x7F, 0x3F | 0x40 | 0x46 | 0x47)
+}
+
+/// One short Indic syllable: a bare vowel or consonant, or a consonant with an
+/// `i` or `e` sign.
+fn is_indic_syllable(classes: &[IndicClass], last: char) -> bool {
+    match classes {
+        [IndicClass::Vowel | IndicClass::Consonant] => true,
+        [IndicClass::Consonant, IndicClass::Sign] => is_letter_name_sign(last),
+        _ => false,
+    }
+}
+
+/// Whether `c` is the vowel a spelled-out Latin letter name starts with: an `e`
+/// (`எம்`, `എൽ`, `एस`) or the long `a` of `R` (`ஆர்`). Other vowels start
+/// common words of the same shape (`അത്` "that").
+fn is_letter_name_vowel(c: char) -> bool {
+    matches!(c as u32 & 0x7F, 0x06 | 0x0D..=0x0F)
+}
+
+/// A vowel followed by one or two dead consonants (`எம்`, `எச்ச்`, `എൽ`), the
+/// last of which may be written without a virama (`एम`, `एस`).
+fn is_indic_letter_name(classes: &[IndicClass]) -> bool {
+    let [IndicClass::Vowel, rest @ ..] = classes else {
+        return false;
+    };
+
+    // A visarga or aytham may follow the vowel (`எஃப்`).
+    let mut rest = rest.strip_prefix(&[IndicClass::Sign]).unwrap_or(rest);
+    let mut units = 0;
+    while !rest.is_empty() {
+        rest = match rest {
+            [IndicClass::Chillu, tail @ ..] => tail,
+            [IndicClass::Consonant, IndicClass::Virama, tail @ ..] => tail,
+            [IndicClass::Consonant] => &[],
+            _ => return false,
+        };
+        units += 1;
+    }
+
+    (1..=2).contains(&units)
+}
+
+/// Classify `word` (the token before a `.`) as a name initial.
+pub(crate) fn classify_initial(word: &str) -> Option<Initial> {
+    let mut chars = word.chars();
+    let first = chars.next()?;
+
+    if chars.as_str().is_empty() {
+        if first.is_uppercase() {
+            return Some(Initial::Contextual);
+        }
+        if is_georgian_letter(first) {
+            return Some(Initial::Standalone);
+        }
+    }
+
+    indic_class(first)?;
+    let classes: Vec<IndicClass> = word.chars().map(indic_class).collect::<Option<_>>()?;
+    let last = word.chars().next_back()?;
+
+    if is_indic_syllable(&classes, last)
+        || (is_letter_name_vowel(first) && is_indic_letter_name(&classes))
+    {
+        Some(Initial::Paired)
+    } else {
+        None
+    }
+}
+
+/// True iff `s` (after leading whitespace) begins with an initial followed by
+/// `.` and then end-of-string or whitespace. `J. R. Tolkien`, `А. С. Пушкин`
+/// and `கெ. பி.` trigger. `Jones`, `J.R.R.`, and `A.B` do not.
+pub(crate) fn starts_with_initial(s: &str) -> bool {
+    let s = s.trim_start();
+    let Some(dot) = s.find('.') else {
+        return false;
+    };
+
+    s[dot + 1..].chars().next().is_none_or(char::is_whitespace)
+        && classify_initial(&s[..dot]).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_initials_by_script() {
+        assert_eq!(classify_initial("J"), Some(Initial::Contextual));
+        assert_eq!(classify_initial("А"), Some(Initial::Contextual));
+        assert_eq!(classify_initial("Γ"), Some(Initial::Contextual));
+        assert_eq!(classify_initial("ი"), Some(Initial::Standalone));
+        assert_eq!(classify_initial("கெ"), Some(Initial::Paired));
+        assert_eq!(classify_initial("പി"), Some(Initial::Paired));
+        assert_eq!(classify_initial("पी"), Some(Initial::Paired));
+        assert_eq!(classify_initial("எம்"), Some(Initial::Paired));
+        assert_eq!(classify_initial("എൽ"), Some(Initial::Paired));
+        assert_eq!(classify_initial("एस"), Some(Initial::Paired));
+
+        assert_eq!(classify_initial("j"), None);
+        assert_eq!(classify_initial("Jo"), None);
+        assert_eq!(classify_initial("நான்"), None);
+        assert_eq!(classify_initial("அவன்"), None);
+        assert_eq!(classify_initial("இது"), None);
+        assert_eq!(classify_initial("അത്"), None);
+        assert_eq!(classify_initial("है"), None);
+        assert_eq!(classify_initial("போ"), None);
+    }
+
+    #[test]
+    fn detects_a_following_initial() {
+        assert!(starts_with_initial(" R. Tolkien"));
+        assert!(starts_with_initial(" С. Пушкин"));
+        assert!(starts_with_initial(" കെ. ആന്റണി"));
+        assert!(!starts_with_initial(" R.R. Tolkien"));
+        assert!(!starts_with_initial(" Пушкин"));
+    }
+
+    #[test]
+    fn georgian_initials_stay_attached() {
+        assert_eq!(
+            crate::segment("en", "ილია ი. ჭავჭავაძე დაიბადა. იგი მწერალი იყო."),
+            vec!["ილია ი. ჭავჭავაძე დაიბადა. ", "იგი მწერალი იყო."]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+// Inverted question and exclamation marks. Spanish, Catalan and Galician open a question
+// with `¿` and an exclamation with `¡`, so the opener marks where a sentence starts and
+// which closer ends it. The span from an opener to its closer is skipped like a quote, so an
+// abbreviation inside it (`¿Llegas a las 5 p. m. El lunes?`) cannot split it, and an opener
+// after a period is a sentence starter (`Vive en EE. UU. ¿Y tú?`). Languages opt in through
+// `Language::uses_inverted_marks`.
+
+use super::language::{SkippableRange, SkippableRangeType};
+
+/// Openers are two bytes in UTF-8 (`C2 BF`, `C2 A1`); no text without a `C2` byte has one.
+const OPENER_LEAD_BYTE: u8 = 0xC2;
+
+/// The closer matching an inverted opener.
+fn closer_for(opener: char) -> Option<char> {
+    match opener {
+        '¿' => Some('?'),
+        '¡' => Some('!'),
+        _ => None,
+    }
+}
+
+/// True when `next_word_approx` (after leading whitespace) starts with `¿` or `¡`.
+pub(crate) fn starts_with_inverted_opener(next_word_approx: &str) -> bool {
+    next_word_approx.trim_start().starts_with(['¿', '¡'])
+}
+
+/// Push a skippable range from every `¿` or `¡` through its closer. Nested pairs
+/// (`¡Ay, ¿qué hiciste?!`) each get a range. A closer only matches the innermost open
+/// opener of its kind, and openers left unclosed at the end of `text` get none.
+pub(crate) fn collect_inverted_ranges(text: &str, out: &mut Vec<SkippableRange>) {
+    if memchr::memchr(OPENER_LEAD_BYTE, text.as_bytes()).is_none() {
+        return;
+    }
+
+    let mut open: Vec<(usize, char)> = Vec::new();
+
+    for (idx, c) in text.char_indices() {
+        if let Some(closer) = closer_for(c) {
+            open.push((idx, closer));
+        } else if matches!(c, '?' | '!')
+            && let Some(&(start, closer)) = open.last()
+            && closer == c
+        {
+            open.pop();
+            out.push(SkippableRange::new(
+                start,
+                idx + c.len_utf8(),
+                SkippableRangeType::Inverted,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::languages::{Catalan, English, Galician, Language, Portuguese, Spanish};
+
+    fn spans(text: &str) -> Vec<&str> {
+        let mut out = Vec::new();
+        collect_inverted_ranges(text, &mut out);
+        out.iter().map(|r| &text[r.start..r.end]).collect()
+    }
+
+    #[test]
+    fn pairs_openers_with_their_closers() {
+        assert_eq!(spans("¡Hola! ¿Qué tal?"), vec!["¡Hola!", "¿Qué tal?"]);
+        assert_eq!(
+            spans("¡Ay, ¿qué hiciste?!"),
+            vec!["¿qué hiciste?", "¡Ay, ¿qué hiciste?!"]
+        );
+        assert_eq!(
+            spans("¡¿Qué dices?! Nada."),
+            vec!["¿Qué dices?", "¡¿Qué dices?!"]
+        );
+        assert_eq!(spans("¿Vienes! Bueno? Sí."), vec!["¿Vienes! Bueno?"]);
+        assert!(spans("¿Vienes. Sí. Hello!").is_empty());
+    }
+
+    #[test]
+    fn only_languages_that_use_them_pair_marks() {
+        let pairs = |lang: &dyn Language| {
+            let mut out = Vec::new();
+            lang.get_skippable_ranges("¿Qué tal?", &mut out);
+            out.iter()
+                .any(|r| r.range_type == SkippableRangeType::Inverted)
+        };
+
+        assert!(pairs(&Spanish {}));
+        assert!(pairs(&Catalan {}));
+        assert!(pairs(&Galician {}));
+        assert!(!pairs(&Portuguese {}));
+        assert!(!pairs(&English {}));
+    }
+}
@@ -2,18 +2,57 @@ use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 use super::Language;
-use super::parse_lowercase_word_list;
+use super::language::starts_with_lowercase_or_digit;
+use super::leading_markers::LeadingMarkerTable;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list, parse_word_list};
 
 #[derive(Debug, Clone)]
 pub struct Italian {}
 
 static ITALIAN_ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/it.txt")]));
+
+static ITALIAN_SENTENCE_STARTERS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_word_list([include_str!("./starters/it.txt")]));
+
+static ITALIAN_FRONTING_WORDS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_lowercase_word_list([include_str!("./fronting/it.txt")]));
+
+static ITALIAN_MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/it.txt"
+    )))
+});
+
+static ITALIAN_LEADING_MARKERS: LazyLock<LeadingMarkerTable> =
+    LazyLock::new(|| LeadingMarkerTable::parse(include_str!("./leading_markers/it.txt")));
+
 impl Language for Italian {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &ITALIAN_ABBREVIATIONS
     }
 
+    fn get_sentence_starters(&self) -> &FxHashSet<String> {
+        &ITALIAN_SENTENCE_STARTERS
+    }
+
+    fn get_fronting_words(&self) -> &FxHashSet<String> {
+        &ITALIAN_FRONTING_WORDS
+    }
+
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &ITALIAN_MARKERS
+    }
+
+    fn get_leading_markers(&self) -> &'static LeadingMarkerTable {
+        &ITALIAN_LEADING_MARKERS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("it")
+    }
+
     fn get_last_word<'a>(&self, text: &'a str) -> &'a str {
         let words: Vec<&str> = text
             .split(|c: char| c.is_whitespace() || c == '.')
@@ -29,8 +68,7 @@ impl Language for Italian {
     }
 
     fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        let regex = regex::Regex::new(r"^[0-9a-z]").unwrap();
-        regex.is_match(text_after_boundary)
+        starts_with_lowercase_or_digit(text_after_boundary)
     }
 }
 
@@ -8,6 +8,10 @@ pub struct Japanese {}
 static JAPANESE_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(FxHashSet::default);
 
 impl Language for Japanese {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("ja")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &JAPANESE_ABBREVIATIONS
     }
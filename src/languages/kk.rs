@@ -1,10 +1,10 @@
 use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
-use regex::Regex;
-
 use super::Language;
-use super::parse_lowercase_word_list;
+use super::language::continues_after_nonword;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list};
 
 #[derive(Debug, Clone)]
 pub struct Kazakh {}
@@ -12,15 +12,25 @@ pub struct Kazakh {}
 static KAZAKH_ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/kk.txt")]));
 
-// Extends the base continuation regex with Cyrillic lowercase range (а-я).
-static KAZAKH_CONTINUE_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\W*[0-9a-zа-я]").unwrap());
+static KAZAKH_MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/kk.txt"
+    )))
+});
 
 impl Language for Kazakh {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("kk")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &KAZAKH_ABBREVIATIONS
     }
 
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &KAZAKH_MARKERS
+    }
+
     fn get_last_word<'a>(&self, text: &'a str) -> &'a str {
         text.split(|c: char| c.is_whitespace() || c == '.')
             .rfind(|word| !word.is_empty())
@@ -28,7 +38,7 @@ impl Language for Kazakh {
     }
 
     fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        KAZAKH_CONTINUE_REGEX.is_match(text_after_boundary)
+        continues_after_nonword(text_after_boundary)
     }
 }
 
@@ -0,0 +1,47 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::Language;
+use super::language::{default_find_boundary, sentence_break_regex};
+
+#[derive(Debug, Clone)]
+pub struct Khmer {}
+
+// Khmer ends sentences with khan `។` and closes sections with bariyoosan `៕`.
+// Spaces separate phrases, not sentences, so they are never breaks.
+static KHMER_SENTENCE_BREAK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| sentence_break_regex(&['។', '៕']));
+
+/// `ល។` is the Khmer "etc.", written with the same khan that ends sentences.
+const KHMER_ETC: &str = "ល";
+
+impl Language for Khmer {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("km")
+    }
+
+    fn get_sentence_break_regex(&self) -> &'static Regex {
+        &KHMER_SENTENCE_BREAK_REGEX
+    }
+
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        if &text[start..end] == "។" && self.get_last_word(&text[..start]) == KHMER_ETC {
+            return None;
+        }
+
+        default_find_boundary(self, text, start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Khmer {}, "tests/km.txt");
+    }
+}
@@ -13,6 +13,10 @@ static ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
     ])
 });
 impl Language for Kannada {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("kn")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &ABBREVIATIONS
     }
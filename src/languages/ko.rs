@@ -0,0 +1,93 @@
+use rustc_hash::FxHashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::language::default_find_boundary;
+use super::{Language, parse_lowercase_word_list};
+
+#[derive(Debug, Clone)]
+pub struct Korean {}
+
+static KOREAN_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
+    parse_lowercase_word_list([
+        include_str!("./abbrev/ko.txt"),
+        include_str!("./abbrev/en.txt"),
+    ])
+});
+
+// Korean dates are written `2024. 3. 15.`: a year, then up to two more
+// period-terminated parts. Group 1 holds the parts after the year.
+static KOREAN_DATE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|[^\d.])\d{4}((?:\.\s?\d{1,2}){0,2})$").unwrap());
+
+static NEXT_DATE_PART_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s?\d{1,2}\.").unwrap());
+
+/// Hangul syllables and jamo.
+fn is_hangul(c: char) -> bool {
+    matches!(c,
+        '\u{AC00}'..='\u{D7A3}'
+        | '\u{1100}'..='\u{11FF}'
+        | '\u{3130}'..='\u{318F}')
+}
+
+/// True when the `.` after `head` belongs to a `2024. 3. 15.` style date.
+fn continues_date(head: &str, tail: &str) -> bool {
+    let Some(caps) = KOREAN_DATE_REGEX.captures(head) else {
+        return false;
+    };
+
+    // After the year alone, only a following month makes it a date.
+    !caps[1].is_empty() || NEXT_DATE_PART_REGEX.is_match(tail)
+}
+
+impl Language for Korean {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("ko")
+    }
+
+    fn get_abbreviations(&self) -> &FxHashSet<String> {
+        &KOREAN_ABBREVIATIONS
+    }
+
+    /// Korean has no case, so continuation is decided by what touches the
+    /// terminator. A hangul predicate followed by whitespace (`했다. `, `요. `)
+    /// ends the sentence whatever comes next, digits and Latin letters
+    /// included. Hangul attached directly after a Latin or digit `.` is a
+    /// particle (`A.I.가`, `Ph.D.를`) and continues it.
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        let head = &text[..start];
+        let tail = &text[end..];
+        let after_hangul = head.chars().next_back().is_some_and(is_hangul);
+
+        if &text[start..end] == "." {
+            if continues_date(head, tail) {
+                return None;
+            }
+
+            if !after_hangul && tail.chars().next().is_some_and(is_hangul) {
+                return None;
+            }
+        }
+
+        let next = tail.trim_start();
+        if after_hangul && next.len() < tail.len() && !next.starts_with('[') {
+            return Some(text.len() - next.len());
+        }
+
+        default_find_boundary(self, text, start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Korean {}, "tests/ko.txt");
+    }
+}
@@ -10,15 +10,31 @@ use crate::constants::PARENS_REGEX;
 use crate::constants::QuotePair;
 use crate::constants::is_sentence_terminator;
 
+use super::calendar::CalendarNames;
+use super::dialogue::{DialogueTurn, classify_dialogue_dash, push_dialogue_turns};
+use super::initials::{Initial, classify_initial, is_georgian_letter, starts_with_initial};
+use super::inverted::{collect_inverted_ranges, starts_with_inverted_opener};
+use super::leading_markers::LeadingMarkerTable;
+use super::ordinals::{OrdinalPolicy, ordinal_continues};
 use super::quotes::{
     OrphanCloserPositions, QuoteMispairing, collect_quote_ranges, extend_past_orphan_closer,
     inner_terminator_boundary, is_symmetric_quote_closer, is_symmetric_quote_mispairing,
     peel_leading_symmetric_quote, tag_quote_mispairing,
 };
+use super::technical::collect_technical_ranges;
 
 use super::trailing_markers::{MarkerTable, classify_trailing_marker, marker_bypasses_suppression};
 
-static DEFAULT_SENTENCE_BREAK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+static DEFAULT_SENTENCE_BREAK_REGEX: LazyLock<Regex> = LazyLock::new(|| sentence_break_regex(&[]));
+
+/// The default sentence-break pattern, with `extra` terminators added to the
+/// global ones (`；`, `؛`, `។`).
+pub(crate) fn sentence_break_regex(extra: &[char]) -> Regex {
+    Regex::new(&sentence_break_pattern(extra)).unwrap()
+}
+
+/// The source of `sentence_break_regex`, for patterns that add alternatives.
+pub(crate) fn sentence_break_pattern(extra: &[char]) -> String {
     // Branch 1 (`\.(?:[ \t]+\.){2,}`) coalesces three-or-more spaced dots
     // (`. . .`, `. . . .`) into one match. Two-dot `. .` is excluded so a
     // period followed by a leading ellipsis (`raak. ...en`) is not eaten as a
@@ -28,32 +44,52 @@ static DEFAULT_SENTENCE_BREAK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     // mixed or homogeneous (`! !`, `? ? ?`, `! ?`, `… !`). `+` rather than
     // `{2,}` is safe here — there's no leading-ellipsis equivalent for `!`/`?`.
     // Both branches must precede the class for leftmost-first alternation.
-    let pattern = format!(
+    format!(
         r"\.(?:[ \t]+\.){{2,}}|[!?…](?:[ \t]+[!?…])+|[{}]+",
-        GLOBAL_SENTENCE_TERMINATORS.iter().collect::<String>()
-    );
-
-    Regex::new(&pattern).unwrap()
-});
-
-// Matches a lowercase letter or digit, optionally preceded by non-word characters
-// (e.g. a space or punctuation). Used by languages that extend the base continuation
-// check with their own month lists.
-static CONTINUE_AFTER_NONWORD_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\W*[0-9a-z]").unwrap());
-
-// Ellipsis continuation: treat a multi-char terminator run as mid-sentence when the follow-up is
-// whitespace + a lowercase letter or digit (`... no`, `. . . what`). Languages with a
-// capitalized word that is ambiguous with a sentence start (English standalone `I`) extend
-// this via the `is_ellipsis_continuation` trait method.
-pub(crate) static ELLIPSIS_CONTINUE_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\s+[0-9a-z]").unwrap());
-
-/// Equivalent to regex `^[0-9a-z]`. Direct byte check is faster for simple pattern.
-fn starts_with_ascii_lowercase_or_digit(s: &str) -> bool {
-    s.as_bytes()
-        .first()
-        .is_some_and(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9'))
+        GLOBAL_SENTENCE_TERMINATORS
+            .iter()
+            .chain(extra)
+            .collect::<String>()
+    )
+}
+
+/// True when `c` reads as the start of a continuing word: an ASCII digit or a
+/// lowercase letter in any cased script (`a`, `é`, `ł`, `я`, `α`, `ա`).
+/// Georgian Mkhedruli is excluded: Unicode files it as lowercase, but it has
+/// no capitals in running text, so it starts sentences too.
+pub(crate) fn is_continuation_char(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_lowercase() || c.is_ascii_digit();
+    }
+
+    c.is_lowercase() && c.is_alphabetic() && !is_georgian_letter(c)
+}
+
+/// True when `s` starts with a lowercase letter or digit (see
+/// [`is_continuation_char`]). ASCII is checked on the first byte alone.
+pub(crate) fn starts_with_lowercase_or_digit(s: &str) -> bool {
+    match s.as_bytes().first() {
+        Some(b) if b.is_ascii() => matches!(b, b'a'..=b'z' | b'0'..=b'9'),
+        Some(_) => s.chars().next().is_some_and(is_continuation_char),
+        None => false,
+    }
+}
+
+/// Like [`starts_with_lowercase_or_digit`], after skipping leading non-word
+/// characters such as spaces, quotes and brackets (`"¿qué`, `(él`).
+pub(crate) fn continues_after_nonword(s: &str) -> bool {
+    starts_with_lowercase_or_digit(
+        s.trim_start_matches(|c: char| !(c.is_alphanumeric() || c == '_')),
+    )
+}
+
+/// Ellipsis continuation: treat a multi-char terminator run as mid-sentence when the follow-up
+/// is whitespace + a lowercase letter or digit (`... no`, `. . . what`). Languages with a
+/// capitalized word that is ambiguous with a sentence start (English standalone `I`) extend
+/// this via the `is_ellipsis_continuation` trait method.
+pub(crate) fn ellipsis_continues(text_after_run: &str) -> bool {
+    let rest = text_after_run.trim_start();
+    rest.len() < text_after_run.len() && starts_with_lowercase_or_digit(rest)
 }
 
 /// If the bytes at `at` start a `\n[\r]*\n` paragraph separator, return
@@ -100,10 +136,6 @@ fn is_code_like_numbered_token(head: &str, next_word_approx: &str) -> bool {
         && next_word_approx.bytes().any(|b| b.is_ascii_digit())
 }
 
-fn is_single_ascii_upper(s: &str) -> bool {
-    s.len() == 1 && s.as_bytes()[0].is_ascii_uppercase()
-}
-
 pub(crate) fn abbreviation_set_contains(set: &FxHashSet<String>, word: &str) -> bool {
     if word.bytes().all(|b| b < 128 && !b.is_ascii_uppercase()) {
         return set.contains(word);
@@ -124,18 +156,99 @@ pub(crate) fn abbreviation_set_contains(set: &FxHashSet<String>, word: &str) ->
     set.contains(word.to_lowercase().as_str())
 }
 
-/// True iff `s` (after leading whitespace) begins with a name-initial token:
-/// a single uppercase ASCII letter, a `.`, then end-of-string or whitespace.
-/// `J. R. Tolkien` triggers. `Jones`, `J.R.R.`, and `A.B` do not.
-fn starts_with_initial(s: &str) -> bool {
-    let mut chars = s.trim_start().chars();
-    let Some(first) = chars.next() else {
+/// Spaced abbreviations (`z. B.`, `и т. д.`, `a. g. p.`) span at most this
+/// many whitespace-separated tokens.
+const MAX_SPACED_ABBREVIATION_TOKENS: usize = 4;
+
+/// The last `n` whitespace-separated tokens of `head`, lowercased and joined
+/// by single spaces, with the byte offset where each token starts.
+fn spaced_tail(head: &str, n: usize, lowercase: impl Fn(&str) -> String) -> (String, Vec<usize>) {
+    let mut tokens: Vec<&str> = head.split_whitespace().rev().take(n).collect();
+    tokens.reverse();
+
+    let mut joined = String::new();
+    let mut starts = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        if !joined.is_empty() {
+            joined.push(' ');
+        }
+        starts.push(joined.len());
+        joined.push_str(&lowercase(token));
+    }
+
+    (joined, starts)
+}
+
+/// Look up `candidate` without leading punctuation (`(z. b`, `«т. е`).
+fn spaced_set_contains(set: &FxHashSet<String>, candidate: &str) -> bool {
+    set.contains(candidate.trim_start_matches(|c: char| !c.is_alphanumeric()))
+}
+
+/// True when `head` ends with a listed abbreviation that has inner spaces,
+/// so the `.` after it is the abbreviation's own (`и т. д`, `z. B`).
+pub(crate) fn spaced_abbreviation_ends(
+    set: &FxHashSet<String>,
+    head: &str,
+    lowercase: impl Fn(&str) -> String,
+) -> bool {
+    if head.ends_with(char::is_whitespace) {
         return false;
-    };
+    }
+
+    // Spaced entries are dotted parts, so an earlier token carries a `.`.
+    let mut tokens = head
+        .split_whitespace()
+        .rev()
+        .take(MAX_SPACED_ABBREVIATION_TOKENS);
+    tokens.next();
+    if !tokens.any(|token| token.contains('.')) {
+        return false;
+    }
+
+    let (joined, starts) = spaced_tail(head, MAX_SPACED_ABBREVIATION_TOKENS, lowercase);
+    starts[..starts.len() - 1]
+        .iter()
+        .any(|&start| spaced_set_contains(set, &joined[start..]))
+}
+
+/// True when the `.` between `head` and `next_word_approx` sits inside a
+/// listed abbreviation that has inner spaces: after `z` in `z. B.`, or after
+/// either `т` in `и т. д.` or `т. е.`.
+pub(crate) fn period_inside_spaced_abbreviation(
+    set: &FxHashSet<String>,
+    head: &str,
+    next_word_approx: &str,
+    lowercase: impl Fn(&str) -> String,
+) -> bool {
+    if head.ends_with(char::is_whitespace) || !next_word_approx.starts_with(char::is_whitespace) {
+        return false;
+    }
 
-    first.is_ascii_uppercase()
-        && chars.next() == Some('.')
-        && chars.next().is_none_or(char::is_whitespace)
+    // Every later part of the abbreviation ends with its own `.`.
+    let next_tokens: Vec<&str> = next_word_approx
+        .split_whitespace()
+        .take(MAX_SPACED_ABBREVIATION_TOKENS - 1)
+        .collect();
+    if !next_tokens.iter().any(|token| token.ends_with('.')) {
+        return false;
+    }
+
+    let (mut joined, starts) = spaced_tail(head, MAX_SPACED_ABBREVIATION_TOKENS - 1, &lowercase);
+    joined.push('.');
+
+    let mut ends = Vec::with_capacity(next_tokens.len());
+    for token in next_tokens {
+        joined.push(' ');
+        joined.push_str(&lowercase(token));
+        if token.ends_with('.') {
+            ends.push(joined.len() - 1);
+        }
+    }
+
+    starts.iter().any(|&start| {
+        ends.iter()
+            .any(|&end| spaced_set_contains(set, &joined[start..end]))
+    })
 }
 
 /// Push `boundary` only if it advances past the last recorded position.
@@ -427,6 +540,12 @@ fn collect_sentence_breaks<L: Language + ?Sized>(
         }
     }
 
+    let dashes = lang.get_dialogue_dashes();
+    if !dashes.is_empty() && push_dialogue_turns(paragraph, dashes, sentence_boundaries) {
+        sentence_boundaries.sort_unstable();
+        sentence_boundaries.dedup();
+    }
+
     merge_list_item_boundaries(sentence_boundaries, &list_starts);
 
     if *sentence_boundaries.last().unwrap() != paragraph.len() {
@@ -535,10 +654,10 @@ fn merge_list_item_boundaries(sentence_boundaries: &mut Vec<usize>, list_starts:
 /// Shared helper for languages that continue sentences before month names.
 ///
 /// Returns `true` if `text` starts with a lowercase letter/digit (after optional
-/// non-word characters), or if its first whitespace-delimited word (case-insensitively
-/// capitalised) is one of the supplied `months`.
-pub fn continues_after_boundary(text: &str, months: &[&str]) -> bool {
-    if CONTINUE_AFTER_NONWORD_REGEX.is_match(text) {
+/// non-word characters), or if its first whitespace-delimited word is a month
+/// of `lang`.
+pub fn continues_after_boundary<L: Language + ?Sized>(text: &str, lang: &L) -> bool {
+    if continues_after_nonword(text) {
         return true;
     }
 
@@ -552,20 +671,134 @@ pub fn continues_after_boundary(text: &str, months: &[&str]) -> bool {
         return false;
     }
 
-    // Build a version with the first character upper-cased (handles non-ASCII safely).
-    let capitalized: String = next_word
-        .chars()
-        .enumerate()
-        .map(|(i, c)| {
-            if i == 0 {
-                c.to_uppercase().to_string()
-            } else {
-                c.to_string()
-            }
-        })
-        .collect();
+    lang.get_calendar_names()
+        .is_month(next_word, |word| lang.lowercase(word))
+}
+
+/// The default `Language::continue_in_next_word`: a lowercase letter or
+/// digit, or a comma (possibly behind a symmetric quote), follows the terminator.
+pub(crate) fn default_continue_in_next_word(text_after_boundary: &str) -> bool {
+    if starts_with_lowercase_or_digit(text_after_boundary) {
+        return true;
+    }
 
-    months.contains(&next_word) || months.contains(&capitalized.as_str())
+    peel_leading_symmetric_quote(text_after_boundary).starts_with(',')
+}
+
+/// True when `text` starts with an Arabic-Indic (`٣`) or Extended Arabic-Indic
+/// (`۳`) digit. Arabic-script languages use it alongside the ASCII digit check
+/// so decimals like `۳.۵` stay in one sentence.
+pub(crate) fn starts_with_arabic_indic_digit(text: &str) -> bool {
+    text.chars()
+        .next()
+        .is_some_and(|c| matches!(c, '\u{0660}'..='\u{0669}' | '\u{06F0}'..='\u{06F9}'))
+}
+
+/// The default `Language::find_boundary`, callable from overrides that only
+/// change the outcome for some terminators.
+pub(crate) fn default_find_boundary<L: Language + ?Sized>(
+    lang: &L,
+    text: &str,
+    start: usize,
+    end: usize,
+) -> Option<usize> {
+    let head = &text[..start];
+    let matched = &text[start..end];
+    let next_word_approx = lang.get_next_word_approx(text, end);
+
+    // Gate the regex to only run if `[` is found
+    if memchr::memchr(b'[', next_word_approx.as_bytes()).is_some()
+        && let Some(m) = crate::constants::NUMBERED_REFERENCE_REGEX.find(next_word_approx)
+    {
+        return Some(end + m.end());
+    }
+
+    if lang.terminator_continues(matched, head, next_word_approx) {
+        return None;
+    }
+
+    let last_word = lang.get_last_word(head);
+
+    if matched == "." {
+        if is_code_like_numbered_token(head, next_word_approx) {
+            return None;
+        }
+
+        if period_inside_spaced_abbreviation(
+            lang.get_abbreviations(),
+            head,
+            next_word_approx,
+            |word| lang.lowercase(word),
+        ) {
+            return None;
+        }
+
+        if let Some(policy) = lang.get_ordinal_policy()
+            && ordinal_continues(lang, policy, head, last_word, next_word_approx)
+        {
+            return None;
+        }
+
+        if lang
+            .get_calendar_names()
+            .period_continues_date(head, last_word, next_word_approx)
+        {
+            return None;
+        }
+
+        if lang.period_suppresses_boundary(head, last_word, next_word_approx) {
+            return None;
+        }
+    }
+
+    if lang.is_exclamation_for(last_word) {
+        return None;
+    }
+
+    // Swallow any whitespace after the terminator into the boundary.
+    // Replaces the `^\s+` regex.
+    let trailing_ws = next_word_approx.len() - next_word_approx.trim_start().len();
+    Some(end + trailing_ws)
+}
+
+/// The default `Language::get_skippable_ranges`: quotes, parentheses, email
+/// addresses, technical tokens (URLs, paths, versions) and, where `lang` uses
+/// them, inverted question and exclamation spans, sorted by start.
+pub(crate) fn collect_skippable_ranges<L: Language + ?Sized>(
+    lang: &L,
+    text: &str,
+    out: &mut Vec<SkippableRange>,
+) {
+    out.clear();
+
+    collect_quote_ranges(text, out);
+
+    for mat in PARENS_REGEX.find_iter(text) {
+        out.push(SkippableRange::new(
+            mat.start(),
+            mat.end(),
+            SkippableRangeType::Parentheses,
+        ));
+    }
+
+    for mat in EMAIL_REGEX.find_iter(text) {
+        out.push(SkippableRange::new(
+            mat.start(),
+            mat.end(),
+            SkippableRangeType::Email,
+        ));
+    }
+
+    collect_technical_ranges(text, out);
+    if lang.uses_inverted_marks() {
+        collect_inverted_ranges(text, out);
+    }
+
+    // Sort ranges by start position for more efficient lookups
+    out.sort_unstable_by_key(|r| r.start);
+
+    // Cache mispairing on each quote range for re-use
+    tag_quote_mispairing(text, out);
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -573,6 +806,19 @@ pub enum SkippableRangeType {
     Quote,
     Parentheses,
     Email,
+    /// URLs, domains, IP addresses and DOIs.
+    Url,
+    /// File names and paths with a known extension.
+    Path,
+    /// Version numbers (`1.2.3`, `v2.0`).
+    Version,
+    /// Code tokens in technical text: backtick spans, calls, qualified names and shell
+    /// paths. Only collected by `TechnicalText`.
+    Code,
+    /// Hashtags and mentions with a dot (`@jane.Doe`). Only collected by `SocialText`.
+    Handle,
+    /// A question or exclamation from its inverted opener to its closer (`¿…?`, `¡…!`).
+    Inverted,
     ListItem,
 }
 
@@ -615,7 +861,9 @@ impl SkippableRange {
     }
 }
 
-pub trait Language {
+/// Sentence segmentation rules for one language. Implementations are shared across
+/// threads, so one instance can segment many texts in parallel.
+pub trait Language: Send + Sync {
     /// Returns a reference to the compiled regex pattern that matches sentence terminating
     /// punctuation. The default implementation uses a static LazyLock for zero-cost access.
     fn get_sentence_break_regex(&self) -> &'static Regex {
@@ -751,6 +999,14 @@ pub trait Language {
         &EMPTY_FRONTING
     }
 
+    /// Leading-marker lookup table: abbreviations that only hold before a
+    /// number or list token (`No. 5`, `Vol. IV`). Languages must opt in.
+    /// Returns an empty LeadingMarkerTable by default.
+    #[inline]
+    fn get_leading_markers(&self) -> &'static LeadingMarkerTable {
+        LeadingMarkerTable::empty()
+    }
+
     /// Trailing-marker lookup table.
     /// Languages must opt in.
     /// Returns an empty MarkerTable by default.
@@ -759,10 +1015,50 @@ pub trait Language {
         MarkerTable::empty()
     }
 
+    /// The code of the language whose bundled data this implementation uses (`"de"`).
+    /// Returns `None` by default.
+    fn get_language_code(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Month, weekday and era names that keep dates together (`3 janv. 2020`,
+    /// `44 v. Chr.`). Returns the CLDR table bundled for `get_language_code`, or
+    /// an empty one.
+    fn get_calendar_names(&self) -> &CalendarNames {
+        self.get_language_code()
+            .map_or(CalendarNames::empty(), CalendarNames::for_code)
+    }
+
+    /// How `<number>.` followed by a word is read (`am 3. Oktober`, `19. století`).
+    /// Languages that write ordinals with a period opt in.
+    /// Returns `None` by default.
+    fn get_ordinal_policy(&self) -> Option<&'static OrdinalPolicy> {
+        None
+    }
+
+    /// Dashes that open an utterance in dialogue (`— Ты придёшь? — спросил он.`).
+    /// Returns an empty slice by default.
+    fn get_dialogue_dashes(&self) -> &'static [char] {
+        &[]
+    }
+
+    /// Lowercase `word` for lookups in this language's word lists and calendar
+    /// names. Returns `str::to_lowercase` by default.
+    fn lowercase(&self, word: &str) -> String {
+        word.to_lowercase()
+    }
+
+    /// Whether questions and exclamations open with `¿` and `¡` (`¿Y tú?`). The
+    /// span up to the closer is skipped, and an opener starts a sentence.
+    /// Returns false by default.
+    fn uses_inverted_marks(&self) -> bool {
+        false
+    }
+
     /// Byte offset past the leading run of whitespace/terminators in `word`,
     /// or `None` when `word` continues the current sentence.
     fn get_boundary_extend(&self, word: &str) -> Option<usize> {
-        if self.continue_in_next_word(word.trim()) || CONTINUE_AFTER_NONWORD_REGEX.is_match(word) {
+        if self.continue_in_next_word(word.trim()) || continues_after_nonword(word) {
             return None;
         }
 
@@ -799,17 +1095,19 @@ pub trait Language {
         abbreviation_set_contains(self.get_abbreviations(), last_word)
     }
 
-    /// Detects a name initial: a single uppercase ASCII letter followed by a
-    /// period in a position that looks like part of a name. Returns true when
-    /// the immediately preceding token in `head` starts with an uppercase
-    /// ASCII letter (`Albert I.`, `George W.`) or the immediately following
-    /// token is itself an initial (`J. R. R. Tolkien`, including the
-    /// sentence-initial position where there is no preceding token).
+    /// Detects a name initial: a single capital letter, or an Indic syllable or
+    /// letter name, followed by a period in a position that looks like part of
+    /// a name. Returns true when the immediately preceding token in `head`
+    /// starts with a capital or is itself an initial (`Albert I.`, `А. С.`), or
+    /// the immediately following token is an initial (`J. R. R. Tolkien`,
+    /// including the sentence-initial position where there is no preceding
+    /// token). An Indic initial needs the neighbouring initial to carry its
+    /// period (`എ. കെ.`). Georgian letters are initials wherever they appear.
+    /// See `initials.rs` for the per-script shapes.
     ///
-    /// Conservative: ASCII-only on both sides, so non-Latin scripts are
-    /// unaffected. Caller is expected to gate this on the matched terminator
-    /// being a single `.` and on `last_word` being a single uppercase letter.
-    /// The helper re-checks the latter so it is safe to call standalone.
+    /// Caller is expected to gate this on the matched terminator being a single
+    /// `.`. The helper classifies `last_word` itself so it is safe to call
+    /// standalone.
     fn is_name_initial(&self, head: &str, next_word_approx: &str) -> bool {
         let last_word = self.get_last_word(head);
         self.is_name_initial_for(head, last_word, next_word_approx)
@@ -819,22 +1117,26 @@ pub trait Language {
     /// when the caller already has the trailing word. Used on the hot path in
     /// `find_boundary`.
     fn is_name_initial_for(&self, head: &str, last_word: &str, next_word_approx: &str) -> bool {
-        if !is_single_ascii_upper(last_word) {
-            return false;
-        }
+        let initial = match classify_initial(last_word) {
+            None => return false,
+            Some(Initial::Standalone) => return true,
+            Some(initial) => initial,
+        };
 
         // Preceding-token rule: trim the initial and any separators
         // get_last_word splits on (whitespace, `.`, `/`), then take the
         // trailing word of what's left.
-        let prefix = head[..head.len() - last_word.len()]
-            .trim_end_matches(|c: char| c.is_whitespace() || c == '.' || c == '/');
-
-        if self
-            .get_last_word(prefix)
-            .chars()
-            .next()
-            .is_some_and(|c| c.is_ascii_uppercase())
-        {
+        let before = head[..head.len() - last_word.len()].trim_end();
+        let prefix = before.trim_end_matches(|c: char| c.is_whitespace() || c == '.' || c == '/');
+
+        let previous = self.get_last_word(prefix);
+        let previous_is_initial = classify_initial(previous).is_some();
+        let neighbour = match initial {
+            // A paired initial needs the previous one's period (`എ. കെ.`, not `നീ പോ.`).
+            Initial::Paired => previous_is_initial && before.ends_with('.'),
+            _ => previous.chars().next().is_some_and(char::is_uppercase) || previous_is_initial,
+        };
+        if neighbour {
             return true;
         }
 
@@ -844,8 +1146,13 @@ pub trait Language {
     /// Returns true if the next non-space token in `next_word_approx` is a known
     /// sentence opener for this language. Overrides sentence break suppression of
     /// abbreviation or name-initial paths. A listed starter word strongly signals
-    /// the start of a new sentence.
+    /// the start of a new sentence, and so does an inverted `¿` or `¡` where the
+    /// language uses them.
     fn next_word_is_sentence_starter(&self, next_word_approx: &str) -> bool {
+        if self.uses_inverted_marks() && starts_with_inverted_opener(next_word_approx) {
+            return true;
+        }
+
         let starters = self.get_sentence_starters();
         if starters.is_empty() {
             return false;
@@ -868,7 +1175,7 @@ pub trait Language {
     /// One way override that lets `find_boundary` keep a boundary the abbreviation / name-initial path would
     /// otherwise suppress. Fires when the next word is a registered sentence starter and the trailing token:
     /// - Starts with an uppercase letter: initials (`I.`), names (`Penn.`), acronyms (`BART.`).
-    /// - A known multi dot abbreviation (`w.e.f.`).
+    /// - A known multi dot abbreviation (`w.e.f.`), spaced or not (`и т. д.`).
     /// - A multi character lowercase abbreviation (`etc.`, `man.`).
     fn should_override_abbrev_suppression_for(
         &self,
@@ -880,16 +1187,15 @@ pub trait Language {
             return false;
         }
 
-        let tail_starts_uppercase = last_word
-            .chars()
-            .next()
-            .is_some_and(|c| c.is_ascii_uppercase());
+        let tail_starts_uppercase = last_word.chars().next().is_some_and(char::is_uppercase);
 
         if tail_starts_uppercase {
             return true;
         }
 
-        if self.is_multi_dot_abbreviation(head, last_word.len()) {
+        if self.is_multi_dot_abbreviation(head, last_word.len())
+            || self.is_spaced_abbreviation(head)
+        {
             return true;
         }
 
@@ -907,6 +1213,12 @@ pub trait Language {
         abbreviation_set_contains(self.get_abbreviations(), last_word_full)
     }
 
+    /// True when `head` ends with an abbreviation listed with inner spaces
+    /// (`z. B`, `и т. д`), matched with whitespace normalised.
+    fn is_spaced_abbreviation(&self, head: &str) -> bool {
+        spaced_abbreviation_ends(self.get_abbreviations(), head, |word| self.lowercase(word))
+    }
+
     /// Like `get_last_word`, but keeps internal `.`s so multi-dot
     /// abbreviations (`w.e.f`, `U.S`, `p.m`) are returned whole. Splits only
     /// on whitespace and `/`. Used by abbreviation lookup so the full token
@@ -979,7 +1291,7 @@ pub trait Language {
 
         let head = &paragraph[..start];
         let last_word = self.get_last_word(head);
-        if last_word.is_empty() || is_single_ascii_upper(last_word) {
+        if last_word.is_empty() || classify_initial(last_word).is_some() {
             return false;
         }
 
@@ -1018,17 +1330,22 @@ pub trait Language {
     /// When a lowercase/digit (or comma) follower, an ellipsis continuation, or a spaced `!`/`?`
     /// before a lowercase word occurs after a terminator, suppress the sentence break.
     fn terminator_continues(&self, matched: &str, head: &str, next_word_approx: &str) -> bool {
+        // An attribution clause after a dialogue dash goes on, a reply does not.
+        if let Some(turn) = classify_dialogue_dash(next_word_approx, self.get_dialogue_dashes()) {
+            return turn == DialogueTurn::Attribution;
+        }
+
         if matched.chars().nth(1).is_some() {
             return self.is_ellipsis_continuation(next_word_approx)
                 || (head.chars().next_back().is_some_and(|c| !c.is_whitespace())
-                    && starts_with_ascii_lowercase_or_digit(next_word_approx));
+                    && starts_with_lowercase_or_digit(next_word_approx));
         }
 
         self.continue_in_next_word(next_word_approx)
             // e.g., "Father Came Too ! is a British comedy film".
             || (matches!(matched, "!" | "?")
                 && matches!(head.as_bytes().last(), Some(b' ' | b'\t'))
-                && CONTINUE_AFTER_NONWORD_REGEX.is_match(next_word_approx))
+                && continues_after_nonword(next_word_approx))
     }
 
     /// Whether a `.` terminator should be suppressed.
@@ -1038,8 +1355,15 @@ pub trait Language {
         last_word: &str,
         next_word_approx: &str,
     ) -> bool {
+        // A leading marker is an abbreviation only before its expected follower.
+        let leading = self.get_leading_markers().policy_for(last_word);
+        if leading.is_some_and(|policy| policy.accepts(next_word_approx)) {
+            return true;
+        }
+
         let suppress = self.is_name_initial_for(head, last_word, next_word_approx)
-            || self.is_abbreviation_for(last_word, ".");
+            || (leading.is_none() && self.is_abbreviation_for(last_word, "."))
+            || self.is_spaced_abbreviation(head);
 
         let marker = classify_trailing_marker(head, self.get_trailing_markers());
         if !suppress && marker.is_none() {
@@ -1061,41 +1385,7 @@ pub trait Language {
     /// This is the core logic that distinguishes true sentence boundaries from false positives
     /// like abbreviations or mid-sentence punctuation.
     fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
-        let head = &text[..start];
-        let matched = &text[start..end];
-        let next_word_approx = self.get_next_word_approx(text, end);
-
-        // Gate the regex to only run if `[` is found
-        if memchr::memchr(b'[', next_word_approx.as_bytes()).is_some()
-            && let Some(m) = crate::constants::NUMBERED_REFERENCE_REGEX.find(next_word_approx)
-        {
-            return Some(end + m.end());
-        }
-
-        if self.terminator_continues(matched, head, next_word_approx) {
-            return None;
-        }
-
-        let last_word = self.get_last_word(head);
-
-        if matched == "." {
-            if is_code_like_numbered_token(head, next_word_approx) {
-                return None;
-            }
-
-            if self.period_suppresses_boundary(head, last_word, next_word_approx) {
-                return None;
-            }
-        }
-
-        if self.is_exclamation_for(last_word) {
-            return None;
-        }
-
-        // Swallow any whitespace after the terminator into the boundary.
-        // Replaces the `^\s+` regex.
-        let trailing_ws = next_word_approx.len() - next_word_approx.trim_start().len();
-        Some(end + trailing_ws)
+        default_find_boundary(self, text, start, end)
     }
 
     /// True when text following a multi-char terminator run (`...`, `! ?`,
@@ -1103,7 +1393,7 @@ pub trait Language {
     /// The default treats only whitespace + a lowercase letter/digit as
     /// continuation.
     fn is_ellipsis_continuation(&self, text_after_run: &str) -> bool {
-        ELLIPSIS_CONTINUE_REGEX.is_match(text_after_run)
+        ellipsis_continues(text_after_run)
     }
 
     /// Determines if the text after a potential boundary indicates the sentence should continue.
@@ -1111,11 +1401,7 @@ pub trait Language {
     /// the sentence is continuing rather than starting a new one. This helps avoid breaking
     /// sentences at abbreviations or in the middle of compound sentences.
     fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        if starts_with_ascii_lowercase_or_digit(text_after_boundary) {
-            return true;
-        }
-
-        peel_leading_symmetric_quote(text_after_boundary).starts_with(',')
+        default_continue_in_next_word(text_after_boundary)
     }
 
     /// Identifies ranges of text that should be skipped during sentence boundary detection.
@@ -1124,31 +1410,7 @@ pub trait Language {
     /// of ranges that can be efficiently checked during boundary detection to avoid
     /// false positives within these special text regions.
     fn get_skippable_ranges(&self, text: &str, out: &mut Vec<SkippableRange>) {
-        out.clear();
-
-        collect_quote_ranges(text, out);
-
-        for mat in PARENS_REGEX.find_iter(text) {
-            out.push(SkippableRange::new(
-                mat.start(),
-                mat.end(),
-                SkippableRangeType::Parentheses,
-            ));
-        }
-
-        for mat in EMAIL_REGEX.find_iter(text) {
-            out.push(SkippableRange::new(
-                mat.start(),
-                mat.end(),
-                SkippableRangeType::Email,
-            ));
-        }
-
-        // Sort ranges by start position for more efficient lookups
-        out.sort_unstable_by_key(|r| r.start);
-
-        // Cache mispairing on each quote range for re-use
-        tag_quote_mispairing(text, out);
+        collect_skippable_ranges(self, text, out);
     }
 }
 
@@ -1168,4 +1430,82 @@ mod tests {
         assert_eq!(lang.get_boundary_extend(""), Some(0));
         assert_eq!(lang.get_boundary_extend(" foo"), None);
     }
+
+    #[test]
+    fn continuation_follows_unicode_lowercase() {
+        use super::{continues_after_nonword, ellipsis_continues, starts_with_lowercase_or_digit};
+
+        for word in ["a", "7", "école", "łatwo", "ίσως", "այո", "это", "ılık"] {
+            assert!(starts_with_lowercase_or_digit(word), "{word}");
+        }
+        for word in [
+            "A",
+            "École",
+            "Łukasz",
+            "Ήρθε",
+            "Եվ",
+            "Это",
+            "",
+            "შემდეგ",
+            "次",
+        ] {
+            assert!(!starts_with_lowercase_or_digit(word), "{word}");
+        }
+
+        assert!(continues_after_nonword(" «élève"));
+        assert!(!continues_after_nonword(" «Élève"));
+        assert!(ellipsis_continues(" ίσως"));
+        assert!(!ellipsis_continues("ίσως"));
+    }
+
+    #[test]
+    fn spaced_abbreviations_match_every_dot() {
+        use super::{period_inside_spaced_abbreviation, spaced_abbreviation_ends};
+        use crate::languages::parse_lowercase_word_list;
+
+        let set = parse_lowercase_word_list(["z. B\nи  т. д.\n"]);
+        assert!(set.contains("и т. д"));
+
+        assert!(period_inside_spaced_abbreviation(
+            &set,
+            "Obst, z",
+            " B. Äpfel",
+            str::to_lowercase
+        ));
+        assert!(period_inside_spaced_abbreviation(
+            &set,
+            "книги (и т",
+            " д. Потом",
+            str::to_lowercase
+        ));
+        assert!(!period_inside_spaced_abbreviation(
+            &set,
+            "Obst, z",
+            " C. Äpfel",
+            str::to_lowercase
+        ));
+        assert!(!period_inside_spaced_abbreviation(
+            &set,
+            "Obst z",
+            "B. Äpfel",
+            str::to_lowercase
+        ));
+
+        assert!(spaced_abbreviation_ends(
+            &set,
+            "Obst, z. B",
+            str::to_lowercase
+        ));
+        assert!(spaced_abbreviation_ends(
+            &set,
+            "газеты и т.  д",
+            str::to_lowercase
+        ));
+        assert!(!spaced_abbreviation_ends(
+            &set,
+            "Obst, z. C",
+            str::to_lowercase
+        ));
+        assert!(!spaced_abbreviation_ends(&set, "B", str::to_lowercase));
+    }
 }
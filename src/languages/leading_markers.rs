@@ -0,0 +1,183 @@
+// Some abbreviations lead a number rather than trail one: `No. 5`, `Fig. 3a`, `pp. 12`,
+// `Vol. IV`, `S. 225`. Without the number they are ordinary words that often end a
+// sentence (`Say no. Then leave.`, `I love modern art. Tom does too.`), so an abbreviation
+// list entry alone suppresses too much. These definitions keep the break suppressed only
+// when the follower has the expected shape, and otherwise let the period break as usual.
+// See `leading_markers/en.txt` for the file format.
+use std::sync::LazyLock;
+
+use rustc_hash::FxHashMap;
+
+/// Which follower shapes keep a leading marker attached to what comes next.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LeadingMarkerPolicy {
+    /// A token starting with an ASCII digit (`No. 5`, `pp. 12-14`, `Fig. 3a`).
+    pub digit: bool,
+    /// A Roman numeral in one case (`Vol. IV`, `pp. xii`).
+    pub roman: bool,
+    /// A single letter, optionally bracketed (`Fig. B`, `Art. (b)`, `Abb. c)`).
+    pub list: bool,
+    /// Space-separated tokens that never count as followers, because they are also
+    /// words that start a sentence (`I`, `A` after `art.`).
+    pub except: &'static str,
+}
+
+impl LeadingMarkerPolicy {
+    /// Whether `next_word_approx` starts with a follower this policy accepts.
+    pub(crate) fn accepts(&self, next_word_approx: &str) -> bool {
+        let token = next_word_approx
+            .split_whitespace()
+            .next()
+            .unwrap_or("")
+            .trim_end_matches([',', ';', ':', '.']);
+
+        if self.except.split_whitespace().any(|word| word == token) {
+            return false;
+        }
+
+        (self.digit && token.starts_with(|c: char| c.is_ascii_digit()))
+            || (self.roman && is_roman_numeral(token))
+            || (self.list && is_list_token(token))
+    }
+}
+
+/// `IV`, `xii`, `XIV–XVI`, but not mixed case (`Xi`) or a trailing word (`Ivan`).
+fn is_roman_numeral(token: &str) -> bool {
+    let numeral = token.split(['-', '–']).next().unwrap_or("");
+
+    !numeral.is_empty()
+        && (numeral.chars().all(|c| "IVXLCDM".contains(c))
+            || numeral.chars().all(|c| "ivxlcdm".contains(c)))
+}
+
+/// `A`, `b`, `(b)`, `c)`.
+fn is_list_token(token: &str) -> bool {
+    let inner = token.strip_prefix('(').unwrap_or(token);
+    let inner = inner.strip_suffix(')').unwrap_or(inner);
+
+    let mut chars = inner.chars();
+    chars.next().is_some_and(char::is_alphabetic) && chars.next().is_none()
+}
+
+/// Leading markers of one language, keyed by the word before the `.`.
+#[derive(Debug, Default)]
+pub struct LeadingMarkerTable {
+    /// Markers matched as written (`S`, German `Art`).
+    exact: FxHashMap<&'static str, LeadingMarkerPolicy>,
+    /// Markers matched in any case, keyed lowercased (`no`, `fig`).
+    folded: FxHashMap<String, LeadingMarkerPolicy>,
+}
+
+impl LeadingMarkerTable {
+    pub fn empty() -> &'static Self {
+        static EMPTY: LazyLock<LeadingMarkerTable> = LazyLock::new(LeadingMarkerTable::default);
+
+        &EMPTY
+    }
+
+    /// Parse a bundled leading-marker file: `word | case | followers [| except]`
+    /// per line, with `case` one of `i`/`s`, `followers` any of `digit`, `roman`
+    /// and `list`, and `except` the tokens that never count as followers. Blank
+    /// lines and `//` comments are skipped.
+    pub fn parse(source: &'static str) -> Self {
+        let mut table = Self::default();
+
+        for raw in source.lines() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            let mut parts = line.split('|').map(str::trim);
+            let word = parts.next().expect("split yields at least one element");
+            let case = parts
+                .next()
+                .unwrap_or_else(|| panic!("leading marker line missing `case` field: {raw:?}"));
+            let followers = parts
+                .next()
+                .unwrap_or_else(|| panic!("leading marker line missing followers: {raw:?}"));
+
+            let mut policy = LeadingMarkerPolicy {
+                except: parts.next().unwrap_or(""),
+                ..LeadingMarkerPolicy::default()
+            };
+            for follower in followers.split_whitespace() {
+                match follower {
+                    "digit" => policy.digit = true,
+                    "roman" => policy.roman = true,
+                    "list" => policy.list = true,
+                    other => panic!("leading marker has unknown follower `{other}`: {raw:?}"),
+                }
+            }
+
+            match case {
+                "s" => {
+                    table.exact.insert(word, policy);
+                }
+                "i" => {
+                    table.folded.insert(word.to_lowercase(), policy);
+                }
+                other => panic!("leading marker has unknown case `{other}`: {raw:?}"),
+            }
+        }
+
+        table
+    }
+
+    /// The policy for `last_word` (the word before a `.`), ignoring leading
+    /// brackets and quotes (`(Fig`).
+    pub(crate) fn policy_for(&self, last_word: &str) -> Option<LeadingMarkerPolicy> {
+        let word = last_word.trim_start_matches(|c: char| !c.is_alphanumeric());
+        if word.is_empty() {
+            return None;
+        }
+
+        if let Some(&policy) = self.exact.get(word) {
+            return Some(policy);
+        }
+
+        if self.folded.is_empty() {
+            return None;
+        }
+
+        if word.bytes().all(|b| b.is_ascii_lowercase()) {
+            self.folded.get(word).copied()
+        } else {
+            self.folded.get(&word.to_lowercase()).copied()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policies_accept_their_followers() {
+        let table = LeadingMarkerTable::parse(
+            "// comment\nno | i | digit\nvol | i | digit roman\nfig | i | digit list | I A\nS | s | digit\n",
+        );
+
+        let no = table.policy_for("No").unwrap();
+        assert!(no.accepts(" 5 on the list"));
+        assert!(!no.accepts(" Tom left"));
+        assert!(!no.accepts(" I agree"));
+
+        let vol = table.policy_for("vol").unwrap();
+        assert!(vol.accepts(" IV is out"));
+        assert!(vol.accepts(" xii, p. 3"));
+        assert!(!vol.accepts(" Ivan"));
+
+        let fig = table.policy_for("(Fig").unwrap();
+        assert!(fig.accepts(" 3a shows"));
+        assert!(fig.accepts(" (b) shows"));
+        assert!(fig.accepts(" B shows"));
+        assert!(!fig.accepts(" Trees grow"));
+        assert!(!fig.accepts(" A bird came"));
+        assert!(!fig.accepts(" I think"));
+
+        assert!(table.policy_for("S").is_some());
+        assert!(table.policy_for("s").is_none());
+        assert!(table.policy_for("art").is_none());
+    }
+}
@@ -0,0 +1,55 @@
+use super::space_breaks::{SpaceBreakRules, space_break_language};
+
+#[derive(Debug, Clone)]
+pub struct Lao {}
+
+/// Lao consonants, vowels and tone marks, without the ellipsis `ຯ` (U+0EAF),
+/// the repetition mark `ໆ` (U+0EC6) and the digits.
+fn is_lao_letter(c: char) -> bool {
+    matches!(c,
+        '\u{0E81}'..='\u{0EAE}'
+        | '\u{0EB0}'..='\u{0EC4}'
+        | '\u{0EC8}'..='\u{0ECE}'
+        | '\u{0EDC}'..='\u{0EDF}')
+}
+
+static LAO_RULES: SpaceBreakRules = SpaceBreakRules {
+    is_script_letter: is_lao_letter,
+    continuation_words: &[
+        "ແລະ",
+        "ຫຼື",
+        "ຫລື",
+        "ແຕ່",
+        "ເຊິ່ງ",
+        "ຊຶ່ງ",
+        "ທີ່",
+        "ເພາະ",
+        "ວ່າ",
+        "ໂດຍ",
+        "ກໍ",
+        "ກັບ",
+        "ຂອງ",
+        "ໃນ",
+        "ຈາກ",
+        "ເພື່ອ",
+        "ໃຫ້",
+        "ເມື່ອ",
+        "ຖ້າ",
+        "ຄື",
+        "ເຊັ່ນ",
+    ],
+};
+
+space_break_language!(Lao, "lo", LAO_RULES);
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Lao {}, "tests/lo.txt");
+    }
+}
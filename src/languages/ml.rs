@@ -14,6 +14,10 @@ static MALAYALAM_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
 });
 
 impl Language for Malayalam {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("ml")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &MALAYALAM_ABBREVIATIONS
     }
@@ -3,61 +3,89 @@ mod ar;
 mod bg;
 mod bn;
 mod ca;
+mod calendar;
+mod code;
 mod da;
 mod de;
+mod dialogue;
 mod el;
 mod en;
 mod es;
+mod fa;
 mod fallbacks;
 mod fi;
 mod fr;
 mod fronting;
+mod gl;
 mod gu;
+mod he;
 mod hi;
 mod hy;
+mod initials;
+mod inverted;
 mod it;
 mod ja;
 mod kk;
+mod km;
 mod kn;
+mod ko;
 mod language;
+mod leading_markers;
 mod list_markers;
+mod lo;
 mod ml;
 mod mr;
 mod my;
 mod nl;
+mod ordinals;
 mod pa;
 mod pl;
 mod pt;
 mod quotes;
 mod ru;
 mod sk;
+mod social;
+mod space_breaks;
 mod ta;
 mod te;
+mod technical;
+mod th;
+mod tr;
 mod trailing_markers;
 mod uk;
+mod ur;
+mod wrapper;
+mod zh;
 
 pub use am::Amharic;
 pub use ar::Arabic;
 pub use bg::Bulgarian;
 pub use bn::Bengali;
 pub use ca::Catalan;
+pub use code::TechnicalText;
 pub use da::Danish;
 pub use de::German;
 pub use el::Greek;
 pub use en::English;
 pub use es::Spanish;
-pub use fallbacks::get_fallbacks;
+pub use fa::Persian;
+pub use fallbacks::{LANGUAGE_FALLBACKS, get_fallbacks};
 pub use fi::Finnish;
 pub use fr::French;
+pub use gl::Galician;
 pub use gu::Gujarati;
+pub use he::Hebrew;
 pub use hi::Hindi;
 pub use hy::Armenian;
 pub use it::Italian;
 pub use ja::Japanese;
 pub use kk::Kazakh;
+pub use km::Khmer;
 pub use kn::Kannada;
+pub use ko::Korean;
 pub use language::Language;
 pub(crate) use language::paragraph_breaks;
+pub use lo::Lao;
 pub use ml::Malayalam;
 pub use mr::Marathi;
 pub use my::Burmese;
@@ -67,9 +95,14 @@ pub use pl::Polish;
 pub use pt::Portuguese;
 pub use ru::Russian;
 pub use sk::Slovak;
+pub use social::SocialText;
 pub use ta::Tamil;
 pub use te::Telugu;
+pub use th::Thai;
+pub use tr::Turkish;
 pub use uk::Ukrainian;
+pub use ur::Urdu;
+pub use zh::Chinese;
 
 use rustc_hash::FxHashSet;
 
@@ -85,12 +118,22 @@ pub(crate) fn parse_word_list<'a>(sources: impl IntoIterator<Item = &'a str>) ->
         .collect()
 }
 
+/// Like `parse_word_list`, lowercased. Entries with inner spaces (`z. b`,
+/// `и т. д.`) are normalised to single spaces without a trailing period, the
+/// form `spaced_abbreviation_ends` looks up.
 pub(crate) fn parse_lowercase_word_list<'a>(
     sources: impl IntoIterator<Item = &'a str>,
 ) -> FxHashSet<String> {
     parse_word_list(sources)
         .iter()
-        .map(|s| s.to_lowercase())
+        .map(|s| {
+            if s.contains(char::is_whitespace) {
+                let joined = s.split_whitespace().collect::<Vec<_>>().join(" ");
+                joined.strip_suffix('.').unwrap_or(&joined).to_lowercase()
+            } else {
+                s.to_lowercase()
+            }
+        })
         .collect()
 }
 
@@ -161,59 +204,19 @@ mod tests {
     use std::fs;
 
     use super::*;
+    use crate::fixtures::{normalise_sentences, parse_fixtures};
 
     pub fn run_language_tests<T: Language>(language: T, test_file: &str) {
         let raw = fs::read_to_string(test_file).expect("Failed to read test file");
-        let content = raw.replace("\r\n", "\n");
-        let test_cases: Vec<&str> = content.split("===").collect();
-
-        for case in test_cases {
-            // Explicit '#' comment handling. This lets fixture files include
-            // section headers and structural comments anywhere — not just at
-            // the very top of the file — without silently skipping cases that
-            // happen to share a chunk with leading comments.
-            let cleaned: String = case
-                .lines()
-                .filter(|line| !line.trim_start().starts_with('#'))
-                .collect::<Vec<_>>()
-                .join("\n");
-
-            let cleaned = cleaned.trim();
-            if cleaned.is_empty() {
-                continue;
-            }
-
-            let parts: Vec<&str> = cleaned
-                .split("---")
-                .map(|part| part.trim())
-                .filter(|part| !part.is_empty())
-                .collect();
-
-            if parts.len() != 2 {
-                continue; // Skip malformed test cases
-            }
-
-            // Comparison normalises whitespace on both sides — any run of
-            // whitespace (spaces, tabs, newlines) collapses to a single
-            // space.
-            let normalise = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ");
-            let input = parts[0];
-
-            let expected: Vec<String> = parts[1]
-                .lines()
-                .map(&normalise)
-                .filter(|s| !s.is_empty())
-                .collect();
-
-            let result = language.segment(input);
 
-            let actual: Vec<String> = result
-                .iter()
-                .map(|item| normalise(item))
-                .filter(|s| !s.is_empty())
-                .collect();
+        for case in parse_fixtures(&raw) {
+            let actual = normalise_sentences(language.segment(&case.input));
 
-            assert_eq!(actual, expected, "Failed for input: \n{}", input);
+            assert_eq!(
+                actual, case.expected,
+                "Failed for input at {test_file}:{}: \n{}",
+                case.line, case.input
+            );
         }
     }
 }
@@ -6,6 +6,10 @@ use super::{Hindi, Language};
 pub struct Marathi {}
 
 impl Language for Marathi {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("mr")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         Hindi {}.get_abbreviations()
     }
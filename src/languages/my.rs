@@ -19,6 +19,10 @@ static BURMESE_SENTENCE_BREAK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 });
 
 impl Language for Burmese {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("my")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         English {}.get_abbreviations()
     }
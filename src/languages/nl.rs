@@ -2,17 +2,55 @@ use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 use super::Language;
-use super::parse_lowercase_word_list;
+use super::leading_markers::LeadingMarkerTable;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list, parse_word_list};
 
 #[derive(Debug, Clone)]
 pub struct Dutch {}
 
 static DUTCH_ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/nl.txt")]));
+
+static DUTCH_SENTENCE_STARTERS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_word_list([include_str!("./starters/nl.txt")]));
+
+static DUTCH_FRONTING_WORDS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_lowercase_word_list([include_str!("./fronting/nl.txt")]));
+
+static DUTCH_MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/nl.txt"
+    )))
+});
+
+static DUTCH_LEADING_MARKERS: LazyLock<LeadingMarkerTable> =
+    LazyLock::new(|| LeadingMarkerTable::parse(include_str!("./leading_markers/nl.txt")));
+
 impl Language for Dutch {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &DUTCH_ABBREVIATIONS
     }
+
+    fn get_sentence_starters(&self) -> &FxHashSet<String> {
+        &DUTCH_SENTENCE_STARTERS
+    }
+
+    fn get_fronting_words(&self) -> &FxHashSet<String> {
+        &DUTCH_FRONTING_WORDS
+    }
+
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &DUTCH_MARKERS
+    }
+
+    fn get_leading_markers(&self) -> &'static LeadingMarkerTable {
+        &DUTCH_LEADING_MARKERS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("nl")
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,104 @@
+// Ordinal numbers written as `<number>.` (`am 3. Oktober`, `der 2. Weltkrieg`,
+// `1. Dünya Savaşı`). The period is part of the number, not a sentence
+// end, so a word following it usually continues the sentence. Languages opt in
+// through `Language::get_ordinal_policy` and decide which followers qualify.
+use crate::constants::is_sentence_terminator;
+
+use super::language::Language;
+
+/// How a language reads a `<number>.` followed by a word.
+#[derive(Debug, Clone, Copy)]
+pub struct OrdinalPolicy {
+    /// Longest number read as an ordinal. Longer numbers are usually years or
+    /// amounts, which do end sentences.
+    pub max_digits: usize,
+    /// Whether a capitalised follower keeps the sentence together. Needed where
+    /// nouns are capitalised (`der 2. Weltkrieg`) or ordinals lead proper names
+    /// (`1. Dünya Savaşı`). Lowercase followers and month names always do.
+    pub capitalized_followers: bool,
+    /// Lowercase words, one of which must come right before the number for a
+    /// capitalised follower to continue (`der 2. Weltkrieg`, but not
+    /// `Das Kind war 5. Max war älter.`). Empty accepts any word.
+    pub capitalized_after: &'static [&'static str],
+    /// Capitalised words that can open a sentence but never follow an ordinal,
+    /// such as articles and pronouns. A `<number>.` before one of them (or before
+    /// a listed sentence starter) still ends the sentence.
+    pub breaking_followers: &'static [&'static str],
+}
+
+/// True when the `.` after `head` closes an ordinal that the next word continues.
+pub(crate) fn ordinal_continues<L: Language + ?Sized>(
+    lang: &L,
+    policy: &OrdinalPolicy,
+    head: &str,
+    last_word: &str,
+    next_word_approx: &str,
+) -> bool {
+    if last_word.is_empty()
+        || last_word.len() > policy.max_digits
+        || !last_word.bytes().all(|b| b.is_ascii_digit())
+    {
+        return false;
+    }
+
+    // `1.2.` and `v3.` are versions and enumerations, not ordinals.
+    let before = &head[..head.len() - last_word.len()];
+    let before_number = before.chars().next_back();
+    if before_number.is_some_and(|c| !c.is_whitespace() && c != '(') {
+        return false;
+    }
+
+    let next = next_word_approx.trim_start();
+    let word_end = next
+        .find(|c: char| c.is_whitespace() || c == ',' || is_sentence_terminator(c))
+        .unwrap_or(next.len());
+    let word = &next[..word_end];
+
+    let Some(first) = word.chars().next() else {
+        return false;
+    };
+
+    if first.is_lowercase()
+        || lang
+            .get_calendar_names()
+            .is_month(word, |word| lang.lowercase(word))
+    {
+        return true;
+    }
+
+    first.is_uppercase()
+        && policy.capitalized_followers
+        && (policy.capitalized_after.is_empty() || follows_listed_word(lang, policy, before))
+        && !policy.breaking_followers.contains(&word)
+        && !lang.next_word_is_sentence_starter(next)
+}
+
+/// True when the word ending `before` (the text up to the number) is one of
+/// `policy.capitalized_after`, in any case.
+fn follows_listed_word<L: Language + ?Sized>(
+    lang: &L,
+    policy: &OrdinalPolicy,
+    before: &str,
+) -> bool {
+    let word = before
+        .trim_end_matches(|c: char| c.is_whitespace() || c == '(')
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or("");
+    let word = lang.lowercase(word);
+
+    policy.capitalized_after.contains(&word.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segment;
+
+    #[test]
+    fn dotted_numbers_are_not_ordinals() {
+        assert_eq!(
+            segment("de", "Installiert ist Version 1.2. Neue Funktionen fehlen."),
+            vec!["Installiert ist Version 1.2. ", "Neue Funktionen fehlen."]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use super::Language;
+use super::language::continues_after_boundary;
 use super::parse_lowercase_word_list;
 use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
@@ -8,10 +9,19 @@ pub struct Polish {}
 
 static ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/pl.txt")]));
+
 impl Language for Polish {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &ABBREVIATIONS
     }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("pl")
+    }
+
+    fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
+        continues_after_boundary(text_after_boundary, self)
+    }
 }
 
 #[cfg(test)]
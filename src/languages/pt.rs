@@ -20,6 +20,10 @@ impl Language for Portuguese {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &ABBREVIATIONS
     }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("pt")
+    }
 }
 
 #[cfg(test)]
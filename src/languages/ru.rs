@@ -1,24 +1,59 @@
 use super::Language;
-use super::parse_lowercase_word_list;
+use super::dialogue::DIALOGUE_DASHES;
+use super::leading_markers::LeadingMarkerTable;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list, parse_word_list};
 use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 #[derive(Debug, Clone)]
 pub struct Russian {}
 
-static PATTERN: LazyLock<regex::Regex> =
-    LazyLock::new(|| regex::Regex::new(r"^[0-9a-zа-я]").expect("Failed to compile regex"));
-
 static ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/ru.txt")]));
 
+static SENTENCE_STARTERS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_word_list([include_str!("./starters/ru.txt")]));
+
+static FRONTING_WORDS: LazyLock<FxHashSet<String>> =
+    LazyLock::new(|| parse_lowercase_word_list([include_str!("./fronting/ru.txt")]));
+
+static MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/ru.txt"
+    )))
+});
+
+static LEADING_MARKERS: LazyLock<LeadingMarkerTable> =
+    LazyLock::new(|| LeadingMarkerTable::parse(include_str!("./leading_markers/ru.txt")));
+
 impl Language for Russian {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &ABBREVIATIONS
     }
 
-    fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        PATTERN.is_match(text_after_boundary)
+    fn get_sentence_starters(&self) -> &FxHashSet<String> {
+        &SENTENCE_STARTERS
+    }
+
+    fn get_fronting_words(&self) -> &FxHashSet<String> {
+        &FRONTING_WORDS
+    }
+
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &MARKERS
+    }
+
+    fn get_leading_markers(&self) -> &'static LeadingMarkerTable {
+        &LEADING_MARKERS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("ru")
+    }
+
+    fn get_dialogue_dashes(&self) -> &'static [char] {
+        DIALOGUE_DASHES
     }
 }
 
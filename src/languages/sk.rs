@@ -20,40 +20,17 @@ static SLOVAK_ALL_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(||
     abbreviations
 });
 
-const MONTHS: [&str; 24] = [
-    "Január",
-    "Február",
-    "Marec",
-    "Apríl",
-    "Máj",
-    "Jún",
-    "Júl",
-    "August",
-    "September",
-    "Október",
-    "November",
-    "December",
-    "Januára",
-    "Februára",
-    "Marca",
-    "Apríla",
-    "Mája",
-    "Júna",
-    "Júla",
-    "Augusta",
-    "Septembra",
-    "Októbra",
-    "Novembra",
-    "Decembra",
-];
-
 impl Language for Slovak {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &SLOVAK_ALL_ABBREVIATIONS
     }
 
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("sk")
+    }
+
     fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        continues_after_boundary(text_after_boundary, &MONTHS)
+        continues_after_boundary(text_after_boundary, self)
     }
 }
 
@@ -67,4 +44,12 @@ mod tests {
     fn test_segment() {
         run_language_tests(Slovak {}, "tests/sk.txt");
     }
+
+    #[test]
+    fn czech_ordinals_continue_through_the_slovak_fallback() {
+        assert_eq!(
+            crate::segment("cs", "Narodil se v 19. století v Praze. Zemřel 5. května."),
+            vec!["Narodil se v 19. století v Praze. ", "Zemřel 5. května."]
+        );
+    }
 }
@@ -0,0 +1,264 @@
+// Social-media mode. Posts end sentences with emoji and emoticons as often as with punctuation
+// (`Great game 😀 See you tomorrow`) and follow a terminator with a run of them
+// (`Wow! 🎉🎉 Next`). `SocialText` wraps any language and attaches such a run to the sentence
+// before it, optionally breaks after a run followed by a capitalised word, and skips hashtags
+// and mentions with dots in them (`@jane.Doe`, `#Web3.0`). Emoticons only count when they
+// stand alone (`:)` but not `Note:Dogs`), and their brackets are masked so `:(` does not pair
+// with a later `)` as a parenthesis.
+use std::borrow::Cow;
+use std::sync::{LazyLock, Mutex};
+
+use regex::Regex;
+use rustc_hash::FxHashMap;
+
+use crate::constants::is_sentence_terminator;
+
+use super::language::{Language, SkippableRange, SkippableRangeType};
+use super::technical::trim_trailing_punctuation;
+use super::wrapper::forward_language;
+
+/// An emoji sequence: a pictograph or flag letter, then any modifiers, joiners, variation
+/// selectors, keycaps, tags and further pictographs. `©`, `®`, `™`, `‼` and `⁉` are
+/// pictographs too but read as punctuation.
+const EMOJI: &str = concat!(
+    r"[\p{Extended_Pictographic}\p{Regional_Indicator}&&[^\u{A9}\u{AE}\u{2122}\u{203C}\u{2049}]]",
+    r"[\p{Extended_Pictographic}\p{Regional_Indicator}\p{Emoji_Modifier}\u{200D}\u{FE0F}\u{20E3}\u{E0020}-\u{E007F}]*",
+);
+
+/// A Western emoticon (`:)`, `;-)`, `:'(`, `:D`, `:P`, `:/`) or a heart or face (`<3`, `^_^`).
+const EMOTICON: &str = r"(?:[:;=][-'^]?[()\[\]DPpO/\\|*3]|<3|\^_?\^)";
+
+static RUN: LazyLock<String> =
+    LazyLock::new(|| format!("(?:{EMOJI}|{EMOTICON})(?:[ \t]*(?:{EMOJI}|{EMOTICON}))*"));
+
+/// A run of emoji and emoticons at the start of the text.
+static RUN_PREFIX_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^(?:{})", *RUN)).unwrap());
+
+/// A terminator match that is a run rather than the wrapped language's punctuation.
+static RUN_ONLY_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^(?:{})$", *RUN)).unwrap());
+
+static EMOTICON_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(EMOTICON).unwrap());
+
+/// A hashtag or mention with an inner dot (`#Web3.0`, `@jane.Doe`).
+static HANDLE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[#@][\p{L}\p{N}_]+(?:\.[\p{L}\p{N}_]+)+").unwrap());
+
+/// The wrapped language's terminator regex with runs as an extra alternative. One regex is
+/// compiled per distinct language pattern and kept for the life of the process.
+fn run_break_regex(inner: &'static Regex) -> &'static Regex {
+    static CACHE: LazyLock<Mutex<FxHashMap<&'static str, &'static Regex>>> =
+        LazyLock::new(Default::default);
+
+    let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    cache.entry(inner.as_str()).or_insert_with(|| {
+        let pattern = format!("(?:{})|(?:{})", inner.as_str(), *RUN);
+        Box::leak(Box::new(Regex::new(&pattern).unwrap()))
+    })
+}
+
+/// Whether `text[start..end]` stands alone: an emoticon needs whitespace, a terminator or the
+/// start of the text before it, and any run needs whitespace or the end of the text after it.
+fn is_standalone(text: &str, start: usize, end: usize) -> bool {
+    let starts_with_emoticon = text.as_bytes()[start].is_ascii();
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+
+    (!starts_with_emoticon || before.is_none_or(|c| c.is_whitespace() || is_sentence_terminator(c)))
+        && after.is_none_or(char::is_whitespace)
+}
+
+/// `boundary` moved past a standalone run that starts there and the whitespace after it.
+fn attach_run(text: &str, boundary: usize) -> usize {
+    let Some(run) = RUN_PREFIX_REGEX.find(&text[boundary..]) else {
+        return boundary;
+    };
+
+    let end = boundary + run.end();
+    if !is_standalone(text, boundary, end) {
+        return boundary;
+    }
+
+    let after = &text[end..];
+    end + after.len() - after.trim_start().len()
+}
+
+/// `text` with the brackets of standalone emoticons replaced by `_`, so they do not open or
+/// close a parenthesis range.
+fn mask_emoticons(text: &str) -> Cow<'_, str> {
+    let mut masked: Option<Vec<u8>> = None;
+
+    for mat in EMOTICON_REGEX.find_iter(text) {
+        if !mat.as_str().contains(['(', ')', '[', ']'])
+            || !is_standalone(text, mat.start(), mat.end())
+        {
+            continue;
+        }
+
+        let bytes = masked.get_or_insert_with(|| text.as_bytes().to_vec());
+        for b in &mut bytes[mat.start()..mat.end()] {
+            if matches!(b, b'(' | b')' | b'[' | b']') {
+                *b = b'_';
+            }
+        }
+    }
+
+    match masked {
+        // Only ASCII brackets were replaced with an ASCII byte, so the text is still UTF-8.
+        Some(bytes) => Cow::Owned(String::from_utf8(bytes).expect("masking keeps UTF-8")),
+        None => Cow::Borrowed(text),
+    }
+}
+
+/// Push a skippable range for every hashtag and mention with an inner dot.
+pub(crate) fn collect_handle_ranges(text: &str, out: &mut Vec<SkippableRange>) {
+    if memchr::memchr2(b'#', b'@', text.as_bytes()).is_none() {
+        return;
+    }
+
+    for mat in HANDLE_REGEX.find_iter(text) {
+        // `jane@example.org` is an email address, `&#8230;` an entity.
+        if text[..mat.start()]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '&')
+        {
+            continue;
+        }
+
+        let trimmed = trim_trailing_punctuation(mat.as_str());
+        out.push(SkippableRange::new(
+            mat.start(),
+            mat.start() + trimmed.len(),
+            SkippableRangeType::Handle,
+        ));
+    }
+}
+
+/// Wraps a language to segment social-media text (see the module comment). Every other
+/// decision is the wrapped language's.
+///
+/// ```
+/// use sentencex::{language_factory, segment_with_language};
+/// use sentencex::languages::SocialText;
+///
+/// let language = SocialText::new(language_factory("en"));
+/// assert_eq!(
+///     segment_with_language(&language, "Wow! 🎉🎉 Next week again."),
+///     vec!["Wow! 🎉🎉 ", "Next week again."]
+/// );
+///
+/// let language = SocialText::new(language_factory("en")).with_emoji_boundaries();
+/// assert_eq!(
+///     segment_with_language(&language, "Great game 😀 See you tomorrow"),
+///     vec!["Great game 😀 ", "See you tomorrow"]
+/// );
+/// ```
+pub struct SocialText {
+    inner: Box<dyn Language>,
+    break_regex: &'static Regex,
+    emoji_boundaries: bool,
+}
+
+impl SocialText {
+    pub fn new(inner: Box<dyn Language>) -> Self {
+        let break_regex = inner.get_sentence_break_regex();
+
+        Self {
+            inner,
+            break_regex,
+            emoji_boundaries: false,
+        }
+    }
+
+    /// Also end a sentence after a standalone run of emoji or emoticons that is followed by a
+    /// capitalised word.
+    pub fn with_emoji_boundaries(mut self) -> Self {
+        self.break_regex = run_break_regex(self.inner.get_sentence_break_regex());
+        self.emoji_boundaries = true;
+        self
+    }
+}
+
+impl Language for SocialText {
+    forward_language!();
+
+    fn get_sentence_break_regex(&self) -> &'static Regex {
+        self.break_regex
+    }
+
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        if self.emoji_boundaries && RUN_ONLY_REGEX.is_match(&text[start..end]) {
+            if !is_standalone(text, start, end) {
+                return None;
+            }
+
+            let rest = &text[end..];
+            let next = rest.trim_start();
+            if !next.starts_with(char::is_uppercase) {
+                return None;
+            }
+
+            return Some(end + rest.len() - next.len());
+        }
+
+        let boundary = self.inner.find_boundary(text, start, end)?;
+        Some(attach_run(text, boundary))
+    }
+
+    fn get_skippable_ranges(&self, text: &str, out: &mut Vec<SkippableRange>) {
+        self.inner.get_skippable_ranges(&mask_emoticons(text), out);
+
+        let first = out.len();
+        collect_handle_ranges(text, out);
+        if out.len() > first {
+            out.sort_by_key(|r| r.start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::languages::English;
+    use crate::languages::tests::run_language_tests;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(
+            SocialText::new(Box::new(English {})).with_emoji_boundaries(),
+            "tests/social.txt",
+        );
+    }
+
+    #[test]
+    fn attaches_runs_without_emoji_boundaries() {
+        let language = SocialText::new(Box::new(English {}));
+
+        assert_eq!(
+            crate::segment_with_language(&language, "So good!!! 😍😍 :) Then we left. 👋"),
+            vec!["So good!!! 😍😍 :) ", "Then we left. 👋"]
+        );
+        assert_eq!(
+            crate::segment_with_language(&language, "Great game 😀 See you tomorrow."),
+            vec!["Great game 😀 See you tomorrow."]
+        );
+    }
+
+    #[test]
+    fn masks_standalone_emoticons() {
+        assert_eq!(mask_emoticons("Sad :( ok (x) :-)"), "Sad :_ ok (x) :-_");
+        assert_eq!(mask_emoticons("f(x) = (a:(b))"), "f(x) = (a:(b))");
+    }
+
+    #[test]
+    fn protects_dotted_handles() {
+        let mut out = Vec::new();
+        let text = "Thanks @jane.Doe and #Web3.0. Mail jane@example.org.";
+        collect_handle_ranges(text, &mut out);
+
+        let handles: Vec<&str> = out.iter().map(|r| &text[r.start..r.end]).collect();
+        assert_eq!(handles, vec!["@jane.Doe", "#Web3.0"]);
+    }
+}
@@ -0,0 +1,98 @@
+// Space-delimited sentence breaks for scripts that write no sentence terminator
+// (Thai, Lao). Words run together and a space marks a phrase or sentence end, so
+// a space between two runs of the script is a candidate break. Only long runs
+// on both sides qualify, and not when the next run opens with a word that
+// continues the clause.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::language::sentence_break_pattern;
+
+/// Minimum length, in characters, of the runs on both sides of a breaking space.
+/// Shorter runs are usually list items or names written between spaces
+/// (`ส้ม กล้วย มะม่วง`).
+const MIN_RUN_CHARS: usize = 6;
+
+/// The default terminator pattern plus runs of blanks, which `space_boundary`
+/// then accepts or rejects.
+pub(crate) static SPACE_BREAK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"{}|[ \t]+", sentence_break_pattern(&[]))).unwrap());
+
+/// Script-specific inputs to `space_boundary`.
+pub(crate) struct SpaceBreakRules {
+    /// Letters and combining marks of the script. Digits and the repetition and
+    /// abbreviation marks (`ๆ`, `ฯ`) are left out: a space after them is
+    /// orthographic, not a break.
+    pub is_script_letter: fn(char) -> bool,
+    /// Conjunctions and relative words that continue the current sentence when
+    /// they open the next run.
+    pub continuation_words: &'static [&'static str],
+}
+
+/// True when the match `[start, end)` is a run of blanks only.
+pub(crate) fn is_blank_match(text: &str, start: usize, end: usize) -> bool {
+    text[start..end].bytes().all(|b| matches!(b, b' ' | b'\t'))
+}
+
+/// The boundary for the blank run at `[start, end)`, or `None` when the space
+/// does not end a sentence.
+pub(crate) fn space_boundary(
+    rules: &SpaceBreakRules,
+    text: &str,
+    start: usize,
+    end: usize,
+) -> Option<usize> {
+    let previous_run = text[..start].rsplit(char::is_whitespace).next()?;
+    let next_run = text[end..].split(char::is_whitespace).next()?;
+
+    let is_script_run = |run: &str, edge: Option<char>| {
+        edge.is_some_and(rules.is_script_letter) && run.chars().count() >= MIN_RUN_CHARS
+    };
+
+    if !is_script_run(previous_run, previous_run.chars().next_back())
+        || !is_script_run(next_run, next_run.chars().next())
+    {
+        return None;
+    }
+
+    if rules
+        .continuation_words
+        .iter()
+        .any(|word| next_run.starts_with(word))
+    {
+        return None;
+    }
+
+    Some(end)
+}
+
+/// `impl Language` for a space-breaking script: the language code, the space-aware
+/// break pattern, and `space_boundary` with the script's `SpaceBreakRules` for blank
+/// matches, the default rules for the rest.
+macro_rules! space_break_language {
+    ($language:ident, $code:literal, $rules:expr) => {
+        impl $crate::languages::Language for $language {
+            fn get_language_code(&self) -> Option<&'static str> {
+                Some($code)
+            }
+
+            fn get_sentence_break_regex(&self) -> &'static regex::Regex {
+                &$crate::languages::space_breaks::SPACE_BREAK_REGEX
+            }
+
+            fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+                if $crate::languages::space_breaks::is_blank_match(text, start, end) {
+                    return $crate::languages::space_breaks::space_boundary(
+                        &$rules, text, start, end,
+                    );
+                }
+
+                $crate::languages::language::default_find_boundary(self, text, start, end)
+            }
+        }
+    };
+}
+
+pub(crate) use space_break_language;
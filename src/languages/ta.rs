@@ -2,37 +2,22 @@ use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 use super::Language;
+use super::parse_lowercase_word_list;
 
 #[derive(Debug, Clone)]
 pub struct Tamil {}
 static TAMIL_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
-    let vowel_signs = vec!["ா", "ி", "ீ", "ু", "ূ", "ে", "ে", "ৈ", "ও", "ো", "ৌ"];
-    let vowels = vec!["அ", "ஆ", "இ", "ஈ", "உ", "ஊ", "எ", "ஏ", "ஐ", "ஒ", "ஓ", "ஔ"];
-    let consonants = vec![
-        "க", "ங", "ச", "ஞ", "ட", "ண", "த", "ந", "ப", "ம", "ய", "ர", "ல", "வ", "ழ", "ள", "ற", "ன",
-    ];
-
-    let mut consonant_vowels = Vec::new();
-    for consonant in &consonants {
-        for vowel_sign in &vowel_signs {
-            consonant_vowels.push(format!("{}{}", consonant, vowel_sign));
-        }
-    }
-
-    include_str!("./abbrev/ta.txt")
-        .lines()
-        .chain(include_str!("./abbrev/en.txt").lines())
-        .map(|line| line.trim().to_string())
-        .filter(|line| !line.starts_with("//") && !line.is_empty())
-        .collect::<Vec<String>>()
-        .into_iter()
-        .chain(vowels.into_iter().map(String::from))
-        .chain(consonants.into_iter().map(String::from))
-        .chain(consonant_vowels)
-        .collect()
+    parse_lowercase_word_list([
+        include_str!("./abbrev/ta.txt"),
+        include_str!("./abbrev/en.txt"),
+    ])
 });
 
 impl Language for Tamil {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("ta")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &TAMIL_ABBREVIATIONS
     }
@@ -14,6 +14,10 @@ static TELUGU_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
     ])
 });
 impl Language for Telugu {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("te")
+    }
+
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &TELUGU_ABBREVIATIONS
     }
@@ -0,0 +1,217 @@
+// URLs, domains, IP addresses, DOIs, file names and version numbers carry periods (and the
+// odd `?` or `!`) that never end a sentence: `https://en.wikipedia.org/wiki/Foo.Bar`,
+// `config.YAML`, `10.1000/xyz.ABC`. They become skippable ranges so a terminator inside them
+// is ignored. Trailing punctuation is left outside the range, so the period in
+// `Visit example.com. Then …` still ends the sentence.
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::language::{SkippableRange, SkippableRangeType};
+
+/// A scheme URL (`https://…`, `ftp://…`) or a `www.` host, up to whitespace or a quote.
+static URL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?-u:\b)(?i:(?:https?|ftp)://|www\.)[^\s<>"“”«»]+"#).unwrap());
+
+/// A bare domain with a common top-level domain (`example.com`, `en.wikipedia.org/wiki/Foo`),
+/// an IPv4 address with an optional port, or a DOI (`10.1000/xyz.abc`). Top-level domains are
+/// lowercase only so prose like `it.IT` is left alone.
+static DOMAIN_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r#"(?-u:\b)(?:[A-Za-z0-9](?:[A-Za-z0-9-]*[A-Za-z0-9])?\.)+"#,
+        r#"(?:com|org|net|edu|gov|mil|int|info|biz|io|dev|app|ai|co|uk|de|fr|es|it|nl|ru|jp|cn|au|ca|eu|us)"#,
+        r#"(?-u:\b)(?:/[^\s<>"“”«»]*)?"#,
+        r#"|(?-u:\b)[0-9]{1,3}(?:\.[0-9]{1,3}){3}(?::[0-9]+)?(?-u:\b)"#,
+        r#"|(?-u:\b)10\.[0-9]{4,9}/[^\s<>"“”«»]+"#,
+    ))
+    .unwrap()
+});
+
+/// File extensions recognised after a name. Words like `go`, `so` and `in` are left out, since
+/// `him.Go away` is more likely a missing space than a file.
+const FILE_EXTENSIONS: &[&str] = &[
+    "bat", "bz2", "cc", "cfg", "conf", "cpp", "css", "csv", "db", "dll", "doc", "docx", "dylib",
+    "exe", "gif", "gz", "hpp", "htm", "html", "ini", "java", "jpeg", "jpg", "js", "json", "jsx",
+    "kt", "lock", "md", "mp3", "mp4", "pdf", "php", "png", "ppt", "pptx", "ps1", "py", "rb", "rs",
+    "scss", "sh", "sql", "svg", "swift", "tar", "tgz", "toml", "ts", "tsv", "tsx", "txt", "wav",
+    "webp", "xls", "xlsx", "xml", "xz", "yaml", "yml", "zip",
+];
+
+/// A dotted file name, optionally behind a Unix, home-relative or Windows directory
+/// (`~/src/main.rs`, `C:\a\b.txt`). `is_file_extension` vets the part after the last `.`.
+static FILE_PATH_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:(?-u:\b)[A-Za-z]:\\|~?/|\.\.?/)?(?:[A-Za-z0-9_.-]+[/\\])*[A-Za-z0-9_-]+(?:\.[A-Za-z0-9_-]+)*\.[A-Za-z0-9]+(?-u:\b)",
+    )
+    .unwrap()
+});
+
+/// A known extension in lowercase or all caps (`config.yaml`, `README.MD`, not `Foo.Txt`).
+fn is_file_extension(ext: &str) -> bool {
+    let lower = ext.bytes().all(|b| !b.is_ascii_uppercase());
+    let upper = ext.bytes().all(|b| !b.is_ascii_lowercase());
+
+    (lower || upper)
+        && FILE_EXTENSIONS
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(ext))
+}
+
+/// A version number: three or more numeric parts (`1.2.3`, `2.0.0-rc.1`), or two behind a `v`
+/// (`v1.2`).
+static VERSION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?-u:\b)(?:v?[0-9]+(?:\.[0-9]+){2,}|v[0-9]+\.[0-9]+)(?:[-+][0-9A-Za-z]+(?:\.[0-9A-Za-z]+)*)?(?-u:\b)",
+    )
+    .unwrap()
+});
+
+/// `match` without closing punctuation that belongs to the sentence: `.`, `,`, `!`, `?`, quotes
+/// and a `)` or `]` with no opener inside the match (`(see example.com/a_(b))`).
+pub(crate) fn trim_trailing_punctuation(matched: &str) -> &str {
+    let mut trimmed = matched;
+
+    while let Some(last) = trimmed.chars().next_back() {
+        let strip = match last {
+            '.' | ',' | ';' | ':' | '!' | '?' | '\'' | '’' | '"' | '”' | '»' => true,
+            ')' => trimmed.matches('(').count() < trimmed.matches(')').count(),
+            ']' => trimmed.matches('[').count() < trimmed.matches(']').count(),
+            _ => false,
+        };
+
+        if !strip {
+            break;
+        }
+        trimmed = &trimmed[..trimmed.len() - last.len_utf8()];
+    }
+
+    trimmed
+}
+
+/// True when `text` has a `.` between two alphanumeric bytes, the shape every dotted token needs.
+fn has_inner_dot(text: &str) -> bool {
+    let bytes = text.as_bytes();
+
+    memchr::memchr_iter(b'.', bytes).any(|idx| {
+        idx > 0
+            && bytes[idx - 1].is_ascii_alphanumeric()
+            && bytes.get(idx + 1).is_some_and(u8::is_ascii_alphanumeric)
+    })
+}
+
+/// Push a range per match of `regex`, skipping matches that start inside a range pushed since
+/// `first` (the host of a URL is also a domain) and paths without a known extension.
+fn push_matches(
+    text: &str,
+    regex: &Regex,
+    range_type: SkippableRangeType,
+    out: &mut Vec<SkippableRange>,
+    first: usize,
+) {
+    for mat in regex.find_iter(text) {
+        if range_type == SkippableRangeType::Path
+            && !mat
+                .as_str()
+                .rsplit_once('.')
+                .is_some_and(|(_, ext)| is_file_extension(ext))
+        {
+            continue;
+        }
+
+        if out[first..]
+            .iter()
+            .any(|r| r.start <= mat.start() && mat.start() < r.end)
+        {
+            continue;
+        }
+
+        let trimmed = trim_trailing_punctuation(mat.as_str());
+        if !trimmed.is_empty() {
+            out.push(SkippableRange::new(
+                mat.start(),
+                mat.start() + trimmed.len(),
+                range_type,
+            ));
+        }
+    }
+}
+
+/// Push a skippable range for every URL, domain, IP address, DOI, file path and version
+/// number in `text`.
+pub(crate) fn collect_technical_ranges(text: &str, out: &mut Vec<SkippableRange>) {
+    let first = out.len();
+    let bytes = text.as_bytes();
+    if memchr::memmem::find(bytes, b"://").is_some()
+        || memchr::memmem::find(bytes, b"www.").is_some()
+        || memchr::memmem::find(bytes, b"WWW.").is_some()
+    {
+        push_matches(text, &URL_REGEX, SkippableRangeType::Url, out, first);
+    }
+
+    if !has_inner_dot(text) {
+        return;
+    }
+
+    push_matches(text, &DOMAIN_REGEX, SkippableRangeType::Url, out, first);
+    push_matches(text, &FILE_PATH_REGEX, SkippableRangeType::Path, out, first);
+    push_matches(
+        text,
+        &VERSION_REGEX,
+        SkippableRangeType::Version,
+        out,
+        first,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(text: &str) -> Vec<(&str, SkippableRangeType)> {
+        let mut out = Vec::new();
+        collect_technical_ranges(text, &mut out);
+        out.iter()
+            .map(|r| (&text[r.start..r.end], r.range_type))
+            .collect()
+    }
+
+    #[test]
+    fn recognises_technical_tokens() {
+        use SkippableRangeType::{Path, Url, Version};
+
+        assert_eq!(
+            ranges("See https://en.wikipedia.org/wiki/Foo.Bar."),
+            vec![("https://en.wikipedia.org/wiki/Foo.Bar", Url)]
+        );
+        assert_eq!(
+            ranges("Visit www.example.com. Then"),
+            vec![("www.example.com", Url)]
+        );
+        assert_eq!(
+            ranges("Cite 10.1000/xyz.ABC, then"),
+            vec![("10.1000/xyz.ABC", Url)]
+        );
+        assert_eq!(
+            ranges("Ping 192.168.1.1:8080."),
+            vec![("192.168.1.1:8080", Url)]
+        );
+        assert_eq!(
+            ranges("Edit ~/app/config.YAML now"),
+            vec![("~/app/config.YAML", Path)]
+        );
+        assert_eq!(
+            ranges("Run v1.2 or 2.0.0-rc.1."),
+            vec![("v1.2", Version), ("2.0.0-rc.1", Version)]
+        );
+        assert!(ranges("I saw him.Go away. It was 2.5 km.").is_empty());
+    }
+
+    #[test]
+    fn keeps_balanced_closers() {
+        assert_eq!(
+            trim_trailing_punctuation("example.com/a_(b))."),
+            "example.com/a_(b)"
+        );
+        assert_eq!(trim_trailing_punctuation("example.com/?"), "example.com/");
+    }
+}
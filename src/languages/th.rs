@@ -0,0 +1,55 @@
+use super::space_breaks::{SpaceBreakRules, space_break_language};
+
+#[derive(Debug, Clone)]
+pub struct Thai {}
+
+/// Thai consonants, vowels and tone marks, without `ฯ` (U+0E2F), `฿` (U+0E3F),
+/// `ๆ` (U+0E46) and the digits.
+fn is_thai_letter(c: char) -> bool {
+    matches!(c,
+        '\u{0E01}'..='\u{0E2E}'
+        | '\u{0E30}'..='\u{0E3A}'
+        | '\u{0E40}'..='\u{0E45}'
+        | '\u{0E47}'..='\u{0E4E}')
+}
+
+static THAI_RULES: SpaceBreakRules = SpaceBreakRules {
+    is_script_letter: is_thai_letter,
+    continuation_words: &[
+        "และ",
+        "หรือ",
+        "แต่",
+        "ซึ่ง",
+        "ที่",
+        "เพราะ",
+        "ว่า",
+        "โดย",
+        "ก็",
+        "กับ",
+        "ของ",
+        "ใน",
+        "จาก",
+        "เพื่อ",
+        "ให้",
+        "เมื่อ",
+        "หาก",
+        "ถ้า",
+        "คือ",
+        "เช่น",
+        "ได้แก่",
+    ],
+};
+
+space_break_language!(Thai, "th", THAI_RULES);
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Thai {}, "tests/th.txt");
+    }
+}
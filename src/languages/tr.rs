@@ -0,0 +1,133 @@
+use rustc_hash::FxHashSet;
+use std::sync::LazyLock;
+
+use crate::constants::is_sentence_terminator;
+
+use super::language::default_find_boundary;
+use super::ordinals::OrdinalPolicy;
+use super::{Language, parse_word_list};
+
+#[derive(Debug, Clone)]
+pub struct Turkish {}
+
+/// Lowercase `s` with Turkish rules: dotted `İ` becomes `i` and dotless `I`
+/// becomes `ı`, where `str::to_lowercase` gives `i̇` and `i`.
+fn turkish_lowercase(s: &str) -> String {
+    let mut lower = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            'I' => lower.push('ı'),
+            'İ' => lower.push('i'),
+            _ => lower.extend(c.to_lowercase()),
+        }
+    }
+
+    lower
+}
+
+static TURKISH_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
+    parse_word_list([include_str!("./abbrev/tr.txt")])
+        .iter()
+        .map(|s| turkish_lowercase(s))
+        .collect()
+});
+
+static TURKISH_SENTENCE_STARTERS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
+    parse_word_list([include_str!("./starters/tr.txt")])
+        .iter()
+        .map(|s| turkish_lowercase(s))
+        .collect()
+});
+
+/// Ordinals lead names and titles (`1. Dünya Savaşı`, `15. Madde`), so a
+/// capitalised follower continues unless it is a sentence starter.
+static TURKISH_ORDINALS: OrdinalPolicy = OrdinalPolicy {
+    max_digits: 3,
+    capitalized_followers: true,
+    capitalized_after: &[],
+    breaking_followers: &[],
+};
+
+impl Language for Turkish {
+    fn get_abbreviations(&self) -> &FxHashSet<String> {
+        &TURKISH_ABBREVIATIONS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("tr")
+    }
+
+    fn get_sentence_starters(&self) -> &FxHashSet<String> {
+        &TURKISH_SENTENCE_STARTERS
+    }
+
+    /// Looked up with Turkish case folding. The English list is not consulted: it
+    /// holds Turkish words (`al`, `sen`, `gen`).
+    fn is_abbreviation_for(&self, last_word: &str, separator: &str) -> bool {
+        if self.get_abbreviation_char() != separator || last_word.is_empty() {
+            return false;
+        }
+
+        TURKISH_ABBREVIATIONS.contains(&self.lowercase(last_word))
+    }
+
+    fn lowercase(&self, word: &str) -> String {
+        turkish_lowercase(word)
+    }
+
+    fn get_ordinal_policy(&self) -> Option<&'static OrdinalPolicy> {
+        Some(&TURKISH_ORDINALS)
+    }
+
+    fn next_word_is_sentence_starter(&self, next_word_approx: &str) -> bool {
+        let trimmed = next_word_approx.trim_start();
+        let word_end = trimmed
+            .find(|c: char| c.is_whitespace() || c == ',' || is_sentence_terminator(c))
+            .unwrap_or(trimmed.len());
+
+        let word = &trimmed[..word_end];
+        word.chars().next().is_some_and(char::is_uppercase)
+            && TURKISH_SENTENCE_STARTERS.contains(&self.lowercase(word))
+    }
+
+    /// Dotted abbreviations ending in a non-ASCII letter (`A.Ş.`) are looked up whole.
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        if &text[start..end] == "."
+            && TURKISH_ABBREVIATIONS
+                .contains(&self.lowercase(self.get_last_word_full(&text[..start])))
+        {
+            return None;
+        }
+
+        default_find_boundary(self, text, start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Turkish {}, "tests/tr.txt");
+    }
+
+    #[test]
+    fn turkish_lowercase_handles_dotted_and_dotless_i() {
+        assert_eq!(turkish_lowercase("İSTANBUL"), "istanbul");
+        assert_eq!(turkish_lowercase("IRMAK"), "ırmak");
+        assert_eq!(turkish_lowercase("Doç"), "doç");
+    }
+
+    #[test]
+    fn months_match_in_turkish_uppercase() {
+        let turkish = Turkish {};
+        let calendar = turkish.get_calendar_names();
+
+        assert!(calendar.is_month("MAYIS", |word| turkish.lowercase(word)));
+        assert!(calendar.is_month("KASIM", |word| turkish.lowercase(word)));
+        assert!(!calendar.is_month("MAYIS", str::to_lowercase));
+    }
+}
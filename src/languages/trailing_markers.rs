@@ -38,23 +38,37 @@ pub struct SuffixMatcher {
 
 impl SuffixMatcher {
     /// The text before the suffix when `head` ends with it (the reverse match), else `None`.
-    /// Case-sensitive markers use `str::strip_suffix`. Case-insensitive ones compare the tail bytes.
+    /// Case-sensitive markers use `str::strip_suffix`. Case-insensitive ASCII ones compare the
+    /// tail bytes. Other case-insensitive ones lowercase as many trailing chars as the suffix has.
     #[inline]
     fn strip<'h>(&self, head: &'h str) -> Option<&'h str> {
-        if self.ignore_case {
+        if !self.ignore_case {
+            return head.strip_suffix(self.suffix);
+        }
+
+        if self.suffix.is_ascii() {
             let idx = head.len().checked_sub(self.suffix.len())?;
-            head.as_bytes()[idx..]
+            return head.as_bytes()[idx..]
                 .eq_ignore_ascii_case(self.suffix.as_bytes())
-                .then(|| &head[..idx])
-        } else {
-            head.strip_suffix(self.suffix)
+                .then(|| &head[..idx]);
         }
+
+        let (idx, _) = head
+            .char_indices()
+            .rev()
+            .nth(self.suffix.chars().count() - 1)?;
+
+        head[idx..]
+            .chars()
+            .flat_map(char::to_lowercase)
+            .eq(self.suffix.chars().flat_map(char::to_lowercase))
+            .then(|| &head[..idx])
     }
 }
 
 pub struct MarkerTable {
     markers: Box<[MarkerDef]>,
-    /// Rejects tails whose final two bytes match no marker, so the linear `markers` scan runs only for real candidates.
+    /// Rejects tails whose final two chars match no marker, so the linear `markers` scan runs only for real candidates.
     two_byte_filter: TwoByteFilter,
 }
 
@@ -76,14 +90,26 @@ impl MarkerTable {
     }
 }
 
-/// Folded (lowercased) last-two-byte lookup key. Case folding makes the filter case-insensitive.
+/// Folds a char to one byte of a lookup key: ASCII lowercased, anything else by the low byte of
+/// its lowercase form. Unrelated chars may share a byte. The filter only needs every case variant
+/// of a char to fold alike.
+#[inline]
+fn fold_key_byte(c: char) -> u8 {
+    if c.is_ascii() {
+        c.to_ascii_lowercase() as u8
+    } else {
+        c.to_lowercase().next().unwrap_or(c) as u32 as u8
+    }
+}
+
+/// Folded last-two-char lookup key. Case folding makes the filter case-insensitive.
 /// Case-sensitive markers are re-checked precisely in `strip`.
 #[inline]
-fn two_byte_key(second_last: u8, last: u8) -> usize {
-    ((second_last.to_ascii_lowercase() as usize) << 8) | last.to_ascii_lowercase() as usize
+fn two_char_key(second_last: char, last: char) -> usize {
+    ((fold_key_byte(second_last) as usize) << 8) | fold_key_byte(last) as usize
 }
 
-/// A 65536-bit set of `two_byte_key`.
+/// A 65536-bit set of `two_char_key`.
 struct TwoByteFilter([u64; 1024]);
 
 impl TwoByteFilter {
@@ -100,17 +126,21 @@ impl TwoByteFilter {
     }
 }
 
-/// Marks every folded last two byte of every marker.
+/// Marks the folded last two chars of every marker. A one-char marker (`г`) marks every key
+/// ending in its char.
 fn build_two_byte_filter(markers: &[MarkerDef]) -> TwoByteFilter {
     let mut filter = TwoByteFilter::EMPTY;
 
     for marker in markers {
-        let suffix = marker.matcher.suffix.as_bytes();
-        let last = *suffix.last().expect("marker suffix must be non-empty");
-
-        match suffix {
-            [.., second_last, _] => filter.insert(two_byte_key(*second_last, last)),
-            _ => (0u8..=255).for_each(|hi| filter.insert(two_byte_key(hi, last))),
+        let mut chars = marker.matcher.suffix.chars().rev();
+        let last = chars.next().expect("marker suffix must be non-empty");
+
+        match chars.next() {
+            Some(second_last) => filter.insert(two_char_key(second_last, last)),
+            None => {
+                let lo = fold_key_byte(last) as usize;
+                (0usize..=255).for_each(|hi| filter.insert((hi << 8) | lo));
+            }
         }
     }
 
@@ -157,13 +187,11 @@ pub(crate) fn classify_trailing_marker<'h>(
 ) -> Option<MarkerMatch<'h>> {
     let trimmed = head.trim_end();
 
-    // A marker is at least two bytes, so a shorter head can't match one.
-    // Reject on the folded last two bytes. Words whose final pair matches no marker never reach the scan.
-    let [.., second_last, last] = trimmed.as_bytes() else {
-        return None;
-    };
-
-    let key = two_byte_key(*second_last, *last);
+    // Reject on the folded last two chars. Words whose final pair matches no marker never reach
+    // the scan. One-char markers accept any second-last char, so a one-char head uses a space.
+    let mut chars = trimmed.chars().rev();
+    let last = chars.next()?;
+    let key = two_char_key(chars.next().unwrap_or(' '), last);
     if !table.two_byte_filter.contains(key) {
         return None;
     }
@@ -186,13 +214,15 @@ pub(crate) fn marker_bypasses_suppression<L: Language + ?Sized>(
     let mut chars = next_word.trim_start().chars();
     let first = chars.next();
 
-    // Single-letter capital initial (`P.D.T.`, `J. R. R.`) continues.
-    if first.is_some_and(|c| c.is_ascii_uppercase()) && chars.next() == Some('.') {
+    // Single-letter capital initial (`P.D.T.`, `J. R. R.`, `А. С.`) continues.
+    if first.is_some_and(char::is_uppercase) && chars.next() == Some('.') {
         return false;
     }
 
     match first {
         Some(c) if c.is_ascii_digit() => policy.digit_breaks,
+        // An inverted opener always starts a sentence (`3 p. m. ¡Qué tarde!`).
+        Some('¿' | '¡') if lang.uses_inverted_marks() => true,
         Some(c) if c.is_uppercase() => {
             next_is_starter
                 || (policy.uppercase_breaks
@@ -206,7 +236,10 @@ pub(crate) fn marker_bypasses_suppression<L: Language + ?Sized>(
 #[cfg(test)]
 mod tests {
     use super::super::language::Language;
-    use super::{MarkerDef, MarkerMatch, MarkerPolicy, SuffixMatcher, marker_bypasses_suppression};
+    use super::{
+        MarkerDef, MarkerMatch, MarkerPolicy, MarkerTable, SuffixMatcher, classify_trailing_marker,
+        marker_bypasses_suppression,
+    };
 
     struct StubLang;
     impl Language for StubLang {}
@@ -271,4 +304,23 @@ mod tests {
             &StubLang
         ));
     }
+
+    #[test]
+    fn non_ascii_suffixes_fold_case() {
+        static TABLE: std::sync::LazyLock<MarkerTable> = std::sync::LazyLock::new(|| {
+            MarkerTable::build(crate::languages::parse_markers_list(
+                "г | s | cont | cont | digit-only\nмлн | i | cont\nJh | s | cont\n",
+            ))
+        });
+
+        let found = |head| classify_trailing_marker(head, &TABLE).map(|m| m.def.matcher.suffix);
+
+        assert_eq!(found("в 1999 г"), Some("г"));
+        assert_eq!(found("в 1999 Г"), None);
+        assert_eq!(found("3 МЛН"), Some("млн"));
+        assert_eq!(found("3 Млн"), Some("млн"));
+        assert_eq!(found("во 19. Jh"), Some("Jh"));
+        assert_eq!(found("3 тыс"), None);
+        assert_eq!(found("г"), None);
+    }
 }
@@ -1,24 +1,37 @@
 use super::Language;
-use super::parse_lowercase_word_list;
+use super::dialogue::DIALOGUE_DASHES;
+use super::trailing_markers::MarkerTable;
+use super::{parse_lowercase_word_list, parse_markers_list};
 use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
 #[derive(Debug, Clone)]
 pub struct Ukrainian {}
 
-static PATTERN: LazyLock<regex::Regex> =
-    LazyLock::new(|| regex::Regex::new(r"^[0-9a-zа-яіїєґ]").expect("Failed to compile regex"));
-
 static ABBREVIATIONS: LazyLock<FxHashSet<String>> =
     LazyLock::new(|| parse_lowercase_word_list([include_str!("./abbrev/uk.txt")]));
 
+static MARKERS: LazyLock<MarkerTable> = LazyLock::new(|| {
+    MarkerTable::build(parse_markers_list(include_str!(
+        "./trailing_markers/uk.txt"
+    )))
+});
+
 impl Language for Ukrainian {
     fn get_abbreviations(&self) -> &FxHashSet<String> {
         &ABBREVIATIONS
     }
 
-    fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
-        PATTERN.is_match(text_after_boundary)
+    fn get_trailing_markers(&self) -> &'static MarkerTable {
+        &MARKERS
+    }
+
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("uk")
+    }
+
+    fn get_dialogue_dashes(&self) -> &'static [char] {
+        DIALOGUE_DASHES
     }
 }
 
@@ -0,0 +1,76 @@
+use rustc_hash::FxHashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::language::{
+    abbreviation_set_contains, default_continue_in_next_word, default_find_boundary,
+    sentence_break_regex, starts_with_arabic_indic_digit,
+};
+use super::{Language, parse_lowercase_word_list};
+
+#[derive(Debug, Clone)]
+pub struct Urdu {}
+
+/// Urdu full stop. Also joins the letters of initialisms (`پی۔ایچ۔ڈی`).
+const URDU_FULL_STOP: &str = "۔";
+
+static URDU_ABBREVIATIONS: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
+    parse_lowercase_word_list([
+        include_str!("./abbrev/ur.txt"),
+        include_str!("./abbrev/en.txt"),
+    ])
+});
+
+// The default pattern plus the Arabic semicolon `؛`.
+static URDU_SENTENCE_BREAK_REGEX: LazyLock<Regex> = LazyLock::new(|| sentence_break_regex(&['؛']));
+
+impl Language for Urdu {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("ur")
+    }
+
+    fn get_abbreviations(&self) -> &FxHashSet<String> {
+        &URDU_ABBREVIATIONS
+    }
+
+    fn get_sentence_break_regex(&self) -> &'static Regex {
+        &URDU_SENTENCE_BREAK_REGEX
+    }
+
+    /// A `۔` written tight against the next letter after a listed letter name is
+    /// part of an initialism (`ایم۔اے`), not a full stop.
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        if &text[start..end] == URDU_FULL_STOP
+            && text[end..].chars().next().is_some_and(char::is_alphabetic)
+        {
+            let word = text[..start]
+                .rsplit(|c: char| c.is_whitespace() || c == '۔')
+                .next()
+                .unwrap_or("");
+
+            if !word.is_empty() && abbreviation_set_contains(self.get_abbreviations(), word) {
+                return None;
+            }
+        }
+
+        default_find_boundary(self, text, start, end)
+    }
+
+    fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
+        starts_with_arabic_indic_digit(text_after_boundary)
+            || default_continue_in_next_word(text_after_boundary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Urdu {}, "tests/ur.txt");
+    }
+}
@@ -0,0 +1,154 @@
+// Text-mode wrappers (`TechnicalText`, `SocialText`) hold a `Box<dyn Language>` and change a
+// few decisions on top of it. `forward_language!` writes the `Language` methods they leave to
+// the wrapped language.
+//
+// `get_sentence_boundaries` and `segment` are never forwarded: their default implementations
+// run the segmentation loop against the wrapper, so it sees the wrapper's overrides. No
+// language overrides them. `get_sentence_break_regex`, `find_boundary` and
+// `get_skippable_ranges` are left to each wrapper.
+
+/// Forward to `self.inner` every `Language` method except the segmentation entry points and
+/// the three a wrapper customises.
+macro_rules! forward_language {
+    () => {
+        fn get_abbreviation_char(&self) -> &str {
+            self.inner.get_abbreviation_char()
+        }
+
+        fn get_abbreviations(&self) -> &rustc_hash::FxHashSet<String> {
+            self.inner.get_abbreviations()
+        }
+
+        fn get_sentence_starters(&self) -> &rustc_hash::FxHashSet<String> {
+            self.inner.get_sentence_starters()
+        }
+
+        fn get_fronting_words(&self) -> &rustc_hash::FxHashSet<String> {
+            self.inner.get_fronting_words()
+        }
+
+        fn get_leading_markers(&self) -> &'static super::leading_markers::LeadingMarkerTable {
+            self.inner.get_leading_markers()
+        }
+
+        fn get_trailing_markers(&self) -> &'static super::trailing_markers::MarkerTable {
+            self.inner.get_trailing_markers()
+        }
+
+        fn get_language_code(&self) -> Option<&'static str> {
+            self.inner.get_language_code()
+        }
+
+        fn get_calendar_names(&self) -> &super::calendar::CalendarNames {
+            self.inner.get_calendar_names()
+        }
+
+        fn get_ordinal_policy(&self) -> Option<&'static super::ordinals::OrdinalPolicy> {
+            self.inner.get_ordinal_policy()
+        }
+
+        fn get_dialogue_dashes(&self) -> &'static [char] {
+            self.inner.get_dialogue_dashes()
+        }
+
+        fn lowercase(&self, word: &str) -> String {
+            self.inner.lowercase(word)
+        }
+
+        fn uses_inverted_marks(&self) -> bool {
+            self.inner.uses_inverted_marks()
+        }
+
+        fn get_boundary_extend(&self, word: &str) -> Option<usize> {
+            self.inner.get_boundary_extend(word)
+        }
+
+        fn is_abbreviation(&self, head: &str, tail: &str, separator: &str) -> bool {
+            self.inner.is_abbreviation(head, tail, separator)
+        }
+
+        fn is_abbreviation_for(&self, last_word: &str, separator: &str) -> bool {
+            self.inner.is_abbreviation_for(last_word, separator)
+        }
+
+        fn is_name_initial(&self, head: &str, next_word_approx: &str) -> bool {
+            self.inner.is_name_initial(head, next_word_approx)
+        }
+
+        fn is_name_initial_for(&self, head: &str, last_word: &str, next_word_approx: &str) -> bool {
+            self.inner
+                .is_name_initial_for(head, last_word, next_word_approx)
+        }
+
+        fn next_word_is_sentence_starter(&self, next_word_approx: &str) -> bool {
+            self.inner.next_word_is_sentence_starter(next_word_approx)
+        }
+
+        fn should_override_abbrev_suppression_for(
+            &self,
+            head: &str,
+            last_word: &str,
+            next_is_starter: bool,
+        ) -> bool {
+            self.inner
+                .should_override_abbrev_suppression_for(head, last_word, next_is_starter)
+        }
+
+        fn is_multi_dot_abbreviation(&self, head: &str, tail_len: usize) -> bool {
+            self.inner.is_multi_dot_abbreviation(head, tail_len)
+        }
+
+        fn is_spaced_abbreviation(&self, head: &str) -> bool {
+            self.inner.is_spaced_abbreviation(head)
+        }
+
+        fn get_last_word_full<'a>(&self, text: &'a str) -> &'a str {
+            self.inner.get_last_word_full(text)
+        }
+
+        fn get_last_word<'a>(&self, text: &'a str) -> &'a str {
+            self.inner.get_last_word(text)
+        }
+
+        fn is_exclamation(&self, head: &str, tail: &str) -> bool {
+            self.inner.is_exclamation(head, tail)
+        }
+
+        fn is_exclamation_for(&self, last_word: &str) -> bool {
+            self.inner.is_exclamation_for(last_word)
+        }
+
+        fn has_strong_sentence_break(&self, paragraph: &str, start: usize, end: usize) -> bool {
+            self.inner.has_strong_sentence_break(paragraph, start, end)
+        }
+
+        fn get_next_word_approx<'a>(&self, text: &'a str, start: usize) -> &'a str {
+            self.inner.get_next_word_approx(text, start)
+        }
+
+        fn terminator_continues(&self, matched: &str, head: &str, next_word_approx: &str) -> bool {
+            self.inner
+                .terminator_continues(matched, head, next_word_approx)
+        }
+
+        fn period_suppresses_boundary(
+            &self,
+            head: &str,
+            last_word: &str,
+            next_word_approx: &str,
+        ) -> bool {
+            self.inner
+                .period_suppresses_boundary(head, last_word, next_word_approx)
+        }
+
+        fn is_ellipsis_continuation(&self, text_after_run: &str) -> bool {
+            self.inner.is_ellipsis_continuation(text_after_run)
+        }
+
+        fn continue_in_next_word(&self, text_after_boundary: &str) -> bool {
+            self.inner.continue_in_next_word(text_after_boundary)
+        }
+    };
+}
+
+pub(crate) use forward_language;
@@ -0,0 +1,97 @@
+use rustc_hash::FxHashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::constants::NUMBERED_REFERENCE_REGEX;
+
+use super::language::{default_find_boundary, sentence_break_regex};
+use super::{English, Language};
+
+#[derive(Debug, Clone)]
+pub struct Chinese {}
+
+/// Full-width terminators that end a Chinese sentence whatever follows them.
+/// `．` is left out: it also serves as a decimal point (`３．２９`).
+const CHINESE_TERMINATORS: &[char] = &['。', '！', '？', '；'];
+
+/// The Chinese ellipsis is written doubled; a single `…` is left to the Latin rules.
+const CHINESE_ELLIPSIS: &str = "……";
+
+/// Closing quotes and brackets that belong to the sentence they follow (`。」`, `！）`).
+const CHINESE_CLOSERS: &[char] = &['」', '』', '”', '’', '）', '》', '〉', '】', '〕'];
+
+/// CJK ideographs, radicals, full-width forms and CJK punctuation. Latin words
+/// written against them (`由Dr.`) end at the first such character.
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{2E80}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{FF00}'..='\u{FFEF}'
+        | '\u{20000}'..='\u{2FFFF}')
+}
+
+// The default pattern plus `；` and `…`, which are not in the global
+// terminator table but end Chinese sentences.
+static CHINESE_SENTENCE_BREAK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| sentence_break_regex(&['；', '…']));
+
+impl Language for Chinese {
+    fn get_language_code(&self) -> Option<&'static str> {
+        Some("zh")
+    }
+
+    fn get_abbreviations(&self) -> &FxHashSet<String> {
+        English {}.get_abbreviations()
+    }
+
+    fn get_sentence_break_regex(&self) -> &'static Regex {
+        &CHINESE_SENTENCE_BREAK_REGEX
+    }
+
+    /// Full-width terminators break without needing whitespace, even before Latin
+    /// letters or digits (`。iPhone`, `。2019年`), and take any closing quotes and
+    /// brackets with them. Other terminators belong to embedded Latin text and
+    /// follow the English rules.
+    fn find_boundary(&self, text: &str, start: usize, end: usize) -> Option<usize> {
+        let matched = &text[start..end];
+        if !matched.contains(CHINESE_TERMINATORS) && !matched.contains(CHINESE_ELLIPSIS) {
+            return default_find_boundary(self, text, start, end);
+        }
+
+        let after_closers = text[end..].trim_start_matches(CHINESE_CLOSERS);
+        let mut boundary = text.len() - after_closers.len();
+
+        if let Some(m) = NUMBERED_REFERENCE_REGEX.find(after_closers) {
+            boundary += m.end();
+        }
+
+        Some(text.len() - text[boundary..].trim_start().len())
+    }
+
+    fn get_last_word_full<'a>(&self, text: &'a str) -> &'a str {
+        text.trim_end()
+            .rsplit(|c: char| c.is_whitespace() || c == '/' || is_cjk(c))
+            .next()
+            .expect("str::rsplit always yields at least one element")
+    }
+
+    fn get_last_word<'a>(&self, text: &'a str) -> &'a str {
+        text.trim_end()
+            .rsplit(|c: char| c.is_whitespace() || c == '.' || c == '/' || is_cjk(c))
+            .next()
+            .expect("str::rsplit always yields at least one element")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::tests::run_language_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        run_language_tests(Chinese {}, "tests/zh.txt");
+    }
+}
@@ -1,15 +1,18 @@
 use languages::{
-    Amharic, Arabic, Armenian, Bengali, Bulgarian, Burmese, Catalan, Danish, Dutch, English,
-    Finnish, French, German, Greek, Gujarati, Hindi, Italian, Japanese, Kannada, Kazakh, Language,
-    Malayalam, Marathi, Polish, Portuguese, Punjabi, Russian, Slovak, Spanish, Tamil, Telugu,
-    Ukrainian,
+    Amharic, Arabic, Armenian, Bengali, Bulgarian, Burmese, Catalan, Chinese, Danish, Dutch,
+    English, Finnish, French, Galician, German, Greek, Gujarati, Hebrew, Hindi, Italian, Japanese,
+    Kannada, Kazakh, Khmer, Korean, Language, Lao, Malayalam, Marathi, Persian, Polish, Portuguese,
+    Punjabi, Russian, Slovak, Spanish, Tamil, Telugu, Thai, Turkish, Ukrainian, Urdu,
 };
 
 use languages::paragraph_breaks;
 use rustc_hash::FxHashSet;
 
 mod constants;
+pub mod fixtures;
+pub mod jsonl;
 pub mod languages;
+pub mod metrics;
 
 use serde::Serialize;
 
@@ -24,6 +27,64 @@ pub struct SentenceBoundary<'a> {
     pub is_paragraph_break: bool,
 }
 
+/// Language codes with a dedicated `Language` implementation. Any other code
+/// is resolved through `languages::get_fallbacks`, ending at English.
+pub const SUPPORTED_LANGUAGES: &[&str] = &[
+    "am", "ar", "bg", "bn", "ca", "da", "de", "el", "en", "es", "fa", "fi", "fr", "gl", "gu", "he",
+    "hi", "hy", "it", "ja", "kk", "km", "kn", "ko", "lo", "ml", "mr", "my", "nl", "pa", "pl", "pt",
+    "ru", "sk", "ta", "te", "th", "tr", "uk", "ur", "zh",
+];
+
+/// The dedicated implementation for `language_code`, without fallback resolution.
+fn dedicated_language(language_code: &str) -> Option<Box<dyn Language>> {
+    let language: Box<dyn Language> = match language_code {
+        "am" => Box::new(Amharic {}),
+        "ar" => Box::new(Arabic {}),
+        "bg" => Box::new(Bulgarian {}),
+        "bn" => Box::new(Bengali {}),
+        "ca" => Box::new(Catalan {}),
+        "da" => Box::new(Danish {}),
+        "de" => Box::new(German {}),
+        "el" => Box::new(Greek {}),
+        "en" => Box::new(English {}),
+        "es" => Box::new(Spanish {}),
+        "fa" => Box::new(Persian {}),
+        "fi" => Box::new(Finnish {}),
+        "fr" => Box::new(French {}),
+        "gl" => Box::new(Galician {}),
+        "gu" => Box::new(Gujarati {}),
+        "he" => Box::new(Hebrew {}),
+        "hi" => Box::new(Hindi {}),
+        "hy" => Box::new(Armenian {}),
+        "it" => Box::new(Italian {}),
+        "ja" => Box::new(Japanese {}),
+        "kk" => Box::new(Kazakh {}),
+        "km" => Box::new(Khmer {}),
+        "kn" => Box::new(Kannada {}),
+        "ko" => Box::new(Korean {}),
+        "lo" => Box::new(Lao {}),
+        "ml" => Box::new(Malayalam {}),
+        "mr" => Box::new(Marathi {}),
+        "my" => Box::new(Burmese {}),
+        "nl" => Box::new(Dutch {}),
+        "pa" => Box::new(Punjabi {}),
+        "pl" => Box::new(Polish {}),
+        "pt" => Box::new(Portuguese {}),
+        "ru" => Box::new(Russian {}),
+        "sk" => Box::new(Slovak {}),
+        "ta" => Box::new(Tamil {}),
+        "te" => Box::new(Telugu {}),
+        "th" => Box::new(Thai {}),
+        "tr" => Box::new(Turkish {}),
+        "uk" => Box::new(Ukrainian {}),
+        "ur" => Box::new(Urdu {}),
+        "zh" => Box::new(Chinese {}),
+        _ => return None,
+    };
+
+    Some(language)
+}
+
 pub fn language_factory(language_code: &str) -> Box<dyn Language> {
     let mut current_code = language_code;
     let mut visited = FxHashSet::default();
@@ -35,50 +96,19 @@ pub fn language_factory(language_code: &str) -> Box<dyn Language> {
             visited.insert(current_code);
         }
 
-        match current_code {
-            "am" => return Box::new(Amharic {}),
-            "ar" => return Box::new(Arabic {}),
-            "bg" => return Box::new(Bulgarian {}),
-            "bn" => return Box::new(Bengali {}),
-            "ca" => return Box::new(Catalan {}),
-            "da" => return Box::new(Danish {}),
-            "de" => return Box::new(German {}),
-            "el" => return Box::new(Greek {}),
-            "en" => return Box::new(English {}),
-            "es" => return Box::new(Spanish {}),
-            "fi" => return Box::new(Finnish {}),
-            "fr" => return Box::new(French {}),
-            "gu" => return Box::new(Gujarati {}),
-            "hi" => return Box::new(Hindi {}),
-            "hy" => return Box::new(Armenian {}),
-            "it" => return Box::new(Italian {}),
-            "ja" => return Box::new(Japanese {}),
-            "kk" => return Box::new(Kazakh {}),
-            "kn" => return Box::new(Kannada {}),
-            "ml" => return Box::new(Malayalam {}),
-            "mr" => return Box::new(Marathi {}),
-            "my" => return Box::new(Burmese {}),
-            "nl" => return Box::new(Dutch {}),
-            "pa" => return Box::new(Punjabi {}),
-            "pl" => return Box::new(Polish {}),
-            "pt" => return Box::new(Portuguese {}),
-            "ru" => return Box::new(Russian {}),
-            "sk" => return Box::new(Slovak {}),
-            "ta" => return Box::new(Tamil {}),
-            "te" => return Box::new(Telugu {}),
-            "uk" => return Box::new(Ukrainian {}),
-            _ => {
-                if let Some(fallbacks) = languages::get_fallbacks(current_code) {
-                    for next_code in fallbacks {
-                        if !visited.contains(next_code) {
-                            current_code = next_code;
-                            break;
-                        }
-                    }
-                } else {
-                    current_code = "en"; // Default to English if no fallbacks are found
+        if let Some(language) = dedicated_language(current_code) {
+            return language;
+        }
+
+        if let Some(fallbacks) = languages::get_fallbacks(current_code) {
+            for next_code in fallbacks {
+                if !visited.contains(next_code) {
+                    current_code = next_code;
+                    break;
                 }
             }
+        } else {
+            current_code = "en"; // Default to English if no fallbacks are found
         }
     }
 }
@@ -160,9 +190,13 @@ fn chunk_text(text: &str, chunk_size: usize) -> Vec<(usize, &str)> {
 /// assert_eq!(sentences, vec!["Hello world. ", "This is a test."]);
 /// ```
 pub fn segment<'a>(language_code: &str, text: &'a str) -> Vec<&'a str> {
-    const CHUNK_SIZE: usize = 10 * 1024; // 10KB
+    segment_with_language(language_factory(language_code).as_ref(), text)
+}
 
-    let language = language_factory(language_code);
+/// Same as `segment`, but with an already constructed `language` (for example one
+/// returned by `language_factory` and cached by the caller).
+pub fn segment_with_language<'a>(language: &dyn Language, text: &'a str) -> Vec<&'a str> {
+    const CHUNK_SIZE: usize = 10 * 1024; // 10KB
 
     if text.len() > CHUNK_SIZE {
         let chunks = chunk_text(text, CHUNK_SIZE);
@@ -226,9 +260,16 @@ pub fn get_sentence_boundaries<'a>(
     language_code: &str,
     text: &'a str,
 ) -> Vec<SentenceBoundary<'a>> {
-    const CHUNK_SIZE: usize = 10 * 1024; // 10KB
+    get_sentence_boundaries_with_language(language_factory(language_code).as_ref(), text)
+}
 
-    let language = language_factory(language_code);
+/// Same as `get_sentence_boundaries`, but with an already constructed `language`
+/// (for example one returned by `language_factory` and cached by the caller).
+pub fn get_sentence_boundaries_with_language<'a>(
+    language: &dyn Language,
+    text: &'a str,
+) -> Vec<SentenceBoundary<'a>> {
+    const CHUNK_SIZE: usize = 10 * 1024; // 10KB
 
     if text.len() > CHUNK_SIZE {
         let chunks = chunk_text(text, CHUNK_SIZE);
@@ -319,6 +360,23 @@ mod tests {
         run_language_tests_for_language("zh", "tests/zh.txt");
     }
 
+    #[test]
+    fn test_supported_languages_have_dedicated_implementations() {
+        for code in SUPPORTED_LANGUAGES {
+            assert!(dedicated_language(code).is_some(), "no language for {code}");
+        }
+
+        assert!(dedicated_language("xx").is_none());
+    }
+
+    #[test]
+    fn test_supported_languages_report_their_code() {
+        for code in SUPPORTED_LANGUAGES {
+            let language = dedicated_language(code).unwrap();
+            assert_eq!(language.get_language_code(), Some(*code));
+        }
+    }
+
     #[test]
     fn test_chunk_text_basic() {
         let text = "First paragraph.\n\nSecond paragraph.\n\nThird paragraph.";
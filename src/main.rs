@@ -1,19 +1,38 @@
-use clap::Parser;
-use sentencex::{get_sentence_boundaries, segment};
+use clap::{Parser, Subcommand};
+use sentencex::{get_sentence_boundaries_with_language, segment_with_language};
 use std::fs;
-use std::io::{self, Read};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Instant;
 
+mod cli;
+
+use cli::batch::{BatchSink, collect_inputs, find_output_collision, run_batch};
+use cli::eval::EvalArgs;
+use cli::jsonl::JsonlArgs;
+use cli::modes::ModeArgs;
+use cli::output::{OutputFormat, write_boundaries};
+use cli::serve::ServeArgs;
+
 /// CLI for Sentence Segmentation
 #[derive(Parser, Debug)]
 #[command(name = "sentencex")]
 #[command(about = "A tool for sentence segmentation", long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
 #[clap(version)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Path to input file
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "paths")]
     file: Option<String>,
 
+    /// Files or directories to segment in batch mode. Directories are walked recursively.
+    paths: Vec<PathBuf>,
+
     /// The language of the text
     #[arg(short, long, default_value = "en")]
     language: String,
@@ -21,13 +40,60 @@ struct Cli {
     /// Print debug information including boundary details
     #[arg(short, long)]
     debug: bool,
+
+    #[command(flatten)]
+    modes: ModeArgs,
+
+    /// Output format. `json`, `jsonl` and `tsv` include offsets, the
+    /// boundary symbol and the paragraph flag for every boundary.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Batch mode: only segment directory entries whose file name matches this
+    /// wildcard pattern (`*`, `?`). May be repeated.
+    #[arg(long, value_name = "PATTERN")]
+    include: Vec<String>,
+
+    /// Batch mode: write one result file per input under this directory, mirroring
+    /// the input layout. Without it, results go to stdout as one JSONL stream
+    /// tagged with each source path.
+    #[arg(short, long, value_name = "DIR", requires = "paths")]
+    output_dir: Option<PathBuf>,
+
+    /// Batch mode: number of files to segment in parallel (defaults to the CPU count)
+    #[arg(short, long)]
+    jobs: Option<usize>,
 }
 
-fn main() {
+#[derive(Subcommand, Debug)]
+enum Command {
+    Eval(EvalArgs),
+    Jsonl(JsonlArgs),
+    Serve(ServeArgs),
+}
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
-    let text = match cli.file {
-        Some(file_path) => fs::read_to_string(file_path).expect("Failed to read file"),
+    match cli.command {
+        Some(Command::Eval(args)) => return cli::eval::run(args),
+        Some(Command::Jsonl(args)) => return cli::jsonl::run(args),
+        Some(Command::Serve(args)) => return cli::serve::run(args),
+        None => {}
+    }
+
+    if !cli.paths.is_empty() {
+        return batch(&cli);
+    }
+
+    let text = match &cli.file {
+        Some(file_path) => match fs::read_to_string(file_path) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("error: {file_path}: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
         None => {
             // Read from stdin when no file is provided
             let mut buffer = String::new();
@@ -38,9 +104,11 @@ fn main() {
         }
     };
 
+    let language = cli.modes.language(&cli.language);
+
     if cli.debug {
         let start_time = Instant::now();
-        let boundaries = get_sentence_boundaries(&cli.language, &text);
+        let boundaries = get_sentence_boundaries_with_language(language.as_ref(), &text);
         let elapsed = start_time.elapsed();
 
         eprintln!("Time taken for get_sentence_boundaries(): {:?}", elapsed);
@@ -57,9 +125,16 @@ fn main() {
             println!("  Is Paragraph Break: {}", boundary.is_paragraph_break);
             println!();
         }
+    } else if cli.format != OutputFormat::Text {
+        let boundaries = get_sentence_boundaries_with_language(language.as_ref(), &text);
+        let mut out = BufWriter::new(io::stdout().lock());
+
+        write_boundaries(&mut out, cli.format, &boundaries)
+            .and_then(|_| out.flush())
+            .expect("Failed to write output");
     } else {
         let start_time = Instant::now();
-        let sentences = segment(&cli.language, &text);
+        let sentences = segment_with_language(language.as_ref(), &text);
         let elapsed = start_time.elapsed();
         for sentence in sentences.iter() {
             println!("* {}", sentence);
@@ -68,4 +143,63 @@ fn main() {
         eprintln!("Time taken for segment(): {:?}", elapsed);
         eprintln!("Total sentences: {:?}", sentences.len());
     }
+
+    ExitCode::SUCCESS
+}
+
+/// Segment every file under `cli.paths` in parallel. Per-file failures are
+/// reported on stderr and turn the exit code into a failure without stopping
+/// the remaining files.
+fn batch(cli: &Cli) -> ExitCode {
+    let sink = match &cli.output_dir {
+        Some(dir) => BatchSink::Mirror(dir),
+        None if matches!(cli.format, OutputFormat::Text | OutputFormat::Jsonl) => BatchSink::Stream,
+        None => {
+            eprintln!("error: batch output to stdout is JSONL; use --output-dir for other formats");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(jobs) = cli.jobs
+        && let Err(err) = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build_global()
+    {
+        eprintln!("error: failed to start {jobs} worker threads: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    let errors = AtomicUsize::new(0);
+    let inputs = collect_inputs(&cli.paths, &cli.include, &errors);
+
+    if let BatchSink::Mirror(dir) = sink
+        && let Some((first, second)) = find_output_collision(&inputs)
+    {
+        eprintln!(
+            "error: {} and {} would both be written to the same file under {}",
+            first.display(),
+            second.display(),
+            dir.display()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let language = cli.modes.language(&cli.language);
+    let start_time = Instant::now();
+    run_batch(&inputs, language.as_ref(), cli.format, &sink, &errors);
+    let elapsed = start_time.elapsed();
+
+    let failed = errors.load(Ordering::Relaxed);
+    eprintln!(
+        "Segmented {} files in {:?} ({} errors)",
+        inputs.len(),
+        elapsed,
+        failed
+    );
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
 }
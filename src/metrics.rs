@@ -0,0 +1,337 @@
+//! Segmentation quality metrics for comparing a hypothesis segmentation against
+//! a reference (gold) segmentation of the same text.
+//!
+//! A segmentation is reduced to its boundary offsets: the position where each
+//! sentence ends, excluding the end of the text. Offsets are unit-agnostic —
+//! the offset-list functions work with bytes, characters or tokens as long as
+//! both sides and the text length use the same unit. `evaluate` works on
+//! `SentenceBoundary` lists and measures in characters.
+
+use std::collections::BTreeMap;
+
+use crate::SentenceBoundary;
+
+/// Boundary-level match counts with precision, recall and F1 derived from them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BoundaryScores {
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+}
+
+impl BoundaryScores {
+    /// Add the matches between `reference` and `hypothesis` offsets to the counts.
+    /// Both slices must be sorted.
+    pub fn add(&mut self, reference: &[usize], hypothesis: &[usize]) {
+        let matched = hypothesis
+            .iter()
+            .filter(|h| reference.binary_search(h).is_ok())
+            .count();
+
+        self.true_positives += matched;
+        self.false_positives += hypothesis.len() - matched;
+        self.false_negatives += reference.len() - matched;
+    }
+
+    /// Fraction of hypothesis boundaries that are in the reference.
+    /// 1.0 when the hypothesis has no boundaries.
+    pub fn precision(&self) -> f64 {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_positives,
+        )
+    }
+
+    /// Fraction of reference boundaries found by the hypothesis.
+    /// 1.0 when the reference has no boundaries.
+    pub fn recall(&self) -> f64 {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_negatives,
+        )
+    }
+
+    /// Harmonic mean of precision and recall.
+    pub fn f1(&self) -> f64 {
+        let (p, r) = (self.precision(), self.recall());
+        if p + r == 0.0 {
+            0.0
+        } else {
+            2.0 * p * r / (p + r)
+        }
+    }
+}
+
+/// `numerator / denominator`, or 1.0 when there is nothing to measure.
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        1.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// Score `hypothesis` boundary offsets against `reference` offsets.
+/// Both slices must be sorted.
+pub fn score_offsets(reference: &[usize], hypothesis: &[usize]) -> BoundaryScores {
+    let mut scores = BoundaryScores::default();
+    scores.add(reference, hypothesis);
+    scores
+}
+
+/// Default Pk/WindowDiff window: half the mean reference segment length, at least 1.
+fn default_window(reference: &[usize], len: usize) -> usize {
+    let segments = reference.len() + 1;
+    (len / segments / 2).max(1)
+}
+
+/// Cumulative boundary counts: `counts[i]` is the number of offsets `<= i`.
+fn cumulative_counts(offsets: &[usize], len: usize) -> Vec<usize> {
+    let mut counts = vec![0usize; len + 1];
+    for &offset in offsets.iter().filter(|&&o| o <= len) {
+        counts[offset] += 1;
+    }
+
+    for i in 1..=len {
+        counts[i] += counts[i - 1];
+    }
+
+    counts
+}
+
+/// Slide a window of `k` units over a text of `len` units and return the share
+/// of windows for which `disagrees(reference_count, hypothesis_count)` holds,
+/// where the counts are the boundaries falling inside the window.
+fn window_error(
+    reference: &[usize],
+    hypothesis: &[usize],
+    len: usize,
+    k: Option<usize>,
+    disagrees: impl Fn(usize, usize) -> bool,
+) -> f64 {
+    let k = k.unwrap_or_else(|| default_window(reference, len));
+    if len <= k {
+        return 0.0;
+    }
+
+    let reference_counts = cumulative_counts(reference, len);
+    let hypothesis_counts = cumulative_counts(hypothesis, len);
+
+    let windows = len - k;
+    let errors = (0..windows)
+        .filter(|&i| {
+            let in_reference = reference_counts[i + k] - reference_counts[i];
+            let in_hypothesis = hypothesis_counts[i + k] - hypothesis_counts[i];
+            disagrees(in_reference, in_hypothesis)
+        })
+        .count();
+
+    errors as f64 / windows as f64
+}
+
+/// Pk (Beeferman et al., 1999): the probability that two positions `k` units
+/// apart are wrongly judged to be in the same or in different segments. Lower
+/// is better; 0.0 is a perfect match. `k` defaults to half the mean reference
+/// segment length. `len` is the text length in the offsets' unit.
+pub fn pk(reference: &[usize], hypothesis: &[usize], len: usize, k: Option<usize>) -> f64 {
+    window_error(reference, hypothesis, len, k, |r, h| (r == 0) != (h == 0))
+}
+
+/// WindowDiff (Pevzner and Hearst, 2002): like `pk`, but a window is an error
+/// whenever the number of boundaries inside it differs, so near misses and
+/// extra boundaries are penalized consistently. Lower is better.
+pub fn window_diff(reference: &[usize], hypothesis: &[usize], len: usize, k: Option<usize>) -> f64 {
+    window_error(reference, hypothesis, len, k, |r, h| r != h)
+}
+
+/// Boundary offsets (in characters) of a `SentenceBoundary` list, as used by `evaluate`.
+/// Each sentence ends after its last non-whitespace character, so segmenters that
+/// attach trailing whitespace differently still agree. Paragraph-break entries and
+/// the end of the text are not boundaries.
+pub fn boundary_offsets(boundaries: &[SentenceBoundary]) -> Vec<usize> {
+    boundary_entries(boundaries)
+        .into_iter()
+        .map(|(offset, _)| offset)
+        .collect()
+}
+
+/// Offsets from `boundary_offsets`, paired with each boundary's symbol.
+fn boundary_entries<'a>(boundaries: &[SentenceBoundary<'a>]) -> Vec<(usize, Option<&'a str>)> {
+    let text_end = boundaries.iter().map(|b| b.end_index).max().unwrap_or(0);
+
+    let mut entries: Vec<(usize, Option<&'a str>)> = boundaries
+        .iter()
+        .filter(|b| !b.is_paragraph_break && !b.text.trim().is_empty())
+        .map(|b| {
+            let trimmed = b.text.trim_end().chars().count();
+            (b.start_index + trimmed, b.boundary_symbol)
+        })
+        .filter(|&(offset, _)| offset < text_end)
+        .collect();
+
+    // Trailing whitespace after the last sentence still leaves its trimmed end
+    // short of `text_end`; it ends the text, not a sentence boundary.
+    let last_content_end = boundaries
+        .iter()
+        .filter(|b| !b.text.trim().is_empty())
+        .map(|b| b.start_index + b.text.trim_end().chars().count())
+        .max();
+    entries.retain(|&(offset, _)| Some(offset) != last_content_end);
+
+    entries.sort_unstable_by_key(|&(offset, _)| offset);
+    entries.dedup_by_key(|&mut (offset, _)| offset);
+    entries
+}
+
+/// Full comparison of two segmentations of the same text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation<'a> {
+    pub scores: BoundaryScores,
+    pub pk: f64,
+    pub window_diff: f64,
+    /// Counts split by `boundary_symbol`. True positives and false negatives are
+    /// filed under the reference boundary's symbol, false positives under the
+    /// hypothesis boundary's symbol. `None` collects boundaries without a symbol.
+    pub by_symbol: BTreeMap<Option<&'a str>, BoundaryScores>,
+}
+
+/// Compare `hypothesis` against `reference`, both produced over the same text
+/// (for example by `get_sentence_boundaries` and a gold annotation). Pk and
+/// WindowDiff use the default window.
+///
+/// # Example
+///
+/// ```
+/// use sentencex::get_sentence_boundaries;
+/// use sentencex::metrics::evaluate;
+///
+/// let text = "Dr. Smith arrived. He sat down.";
+/// let reference = get_sentence_boundaries("en", text);
+/// let hypothesis = get_sentence_boundaries("en", text);
+///
+/// let evaluation = evaluate(&reference, &hypothesis);
+/// assert_eq!(evaluation.scores.f1(), 1.0);
+/// assert_eq!(evaluation.pk, 0.0);
+/// ```
+pub fn evaluate<'a>(
+    reference: &[SentenceBoundary<'a>],
+    hypothesis: &[SentenceBoundary<'a>],
+) -> Evaluation<'a> {
+    let len = reference
+        .iter()
+        .chain(hypothesis)
+        .map(|b| b.end_index)
+        .max()
+        .unwrap_or(0);
+
+    let reference_entries = boundary_entries(reference);
+    let hypothesis_entries = boundary_entries(hypothesis);
+    let reference_offsets: Vec<usize> = reference_entries.iter().map(|e| e.0).collect();
+    let hypothesis_offsets: Vec<usize> = hypothesis_entries.iter().map(|e| e.0).collect();
+
+    let mut by_symbol: BTreeMap<Option<&'a str>, BoundaryScores> = BTreeMap::new();
+
+    for &(offset, symbol) in &reference_entries {
+        let counts = by_symbol.entry(symbol).or_default();
+        if hypothesis_offsets.binary_search(&offset).is_ok() {
+            counts.true_positives += 1;
+        } else {
+            counts.false_negatives += 1;
+        }
+    }
+
+    for &(offset, symbol) in &hypothesis_entries {
+        if reference_offsets.binary_search(&offset).is_err() {
+            by_symbol.entry(symbol).or_default().false_positives += 1;
+        }
+    }
+
+    Evaluation {
+        scores: score_offsets(&reference_offsets, &hypothesis_offsets),
+        pk: pk(&reference_offsets, &hypothesis_offsets, len, None),
+        window_diff: window_diff(&reference_offsets, &hypothesis_offsets, len, None),
+        by_symbol,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_sentence_boundaries;
+
+    #[test]
+    fn scores_count_matches_and_misses() {
+        let scores = score_offsets(&[10, 20, 30], &[10, 25, 30, 40]);
+
+        assert_eq!(scores.true_positives, 2);
+        assert_eq!(scores.false_positives, 2);
+        assert_eq!(scores.false_negatives, 1);
+        assert_eq!(scores.precision(), 0.5);
+        assert!((scores.recall() - 2.0 / 3.0).abs() < 1e-12);
+        assert!((scores.f1() - 4.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn empty_segmentations_score_perfectly() {
+        let scores = score_offsets(&[], &[]);
+        assert_eq!(scores.f1(), 1.0);
+        assert_eq!(pk(&[], &[], 50, None), 0.0);
+    }
+
+    #[test]
+    fn pk_and_window_diff_on_offsets() {
+        // Reference segments of 10 units; the hypothesis misses the middle boundary
+        // and adds a near miss.
+        let reference = [10, 20, 30];
+        let hypothesis = [10, 22, 30];
+
+        assert_eq!(pk(&reference, &reference, 40, Some(5)), 0.0);
+        assert_eq!(window_diff(&reference, &reference, 40, Some(5)), 0.0);
+
+        // Windows starting at 15..=16 see the reference boundary but not 22, and
+        // windows 20..=21 see 22 but not 20: 4 of 35 windows disagree.
+        assert!((pk(&reference, &hypothesis, 40, Some(5)) - 4.0 / 35.0).abs() < 1e-12);
+        assert!((window_diff(&reference, &hypothesis, 40, Some(5)) - 4.0 / 35.0).abs() < 1e-12);
+
+        // An extra boundary next to a correct one: Pk only notices the window
+        // starting at 4, WindowDiff also counts windows 5..=8 holding both.
+        assert!((pk(&[10], &[9, 10], 20, Some(5)) - 1.0 / 15.0).abs() < 1e-12);
+        assert!((window_diff(&[10], &[9, 10], 20, Some(5)) - 5.0 / 15.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn boundary_offsets_ignore_trailing_whitespace_and_paragraphs() {
+        let boundaries = get_sentence_boundaries("en", "One. Two.\n\nThree.  ");
+        assert_eq!(boundary_offsets(&boundaries), vec![4, 9]);
+    }
+
+    #[test]
+    fn evaluate_breaks_down_by_symbol() {
+        let text = "Dr. Smith left. Really? Yes! Done.";
+        let reference = get_sentence_boundaries("en", text);
+
+        // A hypothesis that splits after `Dr.` and merges `Really? Yes!`.
+        let hypothesis: Vec<SentenceBoundary> = [(0, 4), (4, 16), (16, 29), (29, 34)]
+            .iter()
+            .map(|&(start, end)| SentenceBoundary {
+                start_index: start,
+                end_index: end,
+                start_byte: start,
+                end_byte: end,
+                text: &text[start..end],
+                boundary_symbol: None,
+                is_paragraph_break: false,
+            })
+            .collect();
+
+        let evaluation = evaluate(&reference, &hypothesis);
+
+        assert_eq!(evaluation.scores.true_positives, 2);
+        assert_eq!(evaluation.scores.false_positives, 1);
+        assert_eq!(evaluation.scores.false_negatives, 1);
+        assert_eq!(evaluation.by_symbol[&Some(".")].true_positives, 1);
+        assert_eq!(evaluation.by_symbol[&Some("?")].false_negatives, 1);
+        assert_eq!(evaluation.by_symbol[&Some("!")].true_positives, 1);
+        assert_eq!(evaluation.by_symbol[&None].false_positives, 1);
+    }
+}